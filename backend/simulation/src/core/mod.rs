@@ -1 +1,3 @@
 pub mod models;
+pub mod numeric;
+pub mod parameters;
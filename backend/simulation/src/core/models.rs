@@ -1,33 +1,202 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::{ELECTRON_MASS_EXACT, ELEMENTARY_CHARGE, EV_TO_KG, G_GRAVITATIONAL, GEV_TO_KG, KG_TO_GEV, MEV_TO_KG};
 use serde::{Deserialize, Serialize};
 
 
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct CosmicLaw {
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Real + Deserialize<'de>"))]
+pub struct CosmicLaw<T = f64> {
     #[allow(non_snake_case)]
-    pub g: f64,
-    pub e: f64,
-    pub alpha_s: f64,
-    pub alpha_w: f64,
+    pub g: T,
+    pub e: T,
+    pub alpha_s: T,
+    pub alpha_w: T,
 
-    pub mass_up_quark: f64,
-    pub mass_down_quark: f64,
-    pub mass_electron: f64,
+    pub mass_up_quark: T,
+    pub mass_down_quark: T,
+    pub mass_electron: T,
 
-    pub mass_charm_quark: f64,
-    pub mass_strange_quark: f64,
-    pub mass_muon: f64,
+    pub mass_charm_quark: T,
+    pub mass_strange_quark: T,
+    pub mass_muon: T,
 
-    pub mass_top_quark: f64,
-    pub mass_bottom_quark: f64,
-    pub mass_tauon: f64,
+    pub mass_top_quark: T,
+    pub mass_bottom_quark: T,
+    pub mass_tauon: T,
 
-    pub spatial_curvature: f64,
-    pub dimensional_ratios: [f64; 4],
-    pub temporal_evolution_rate: f64,
+    pub spatial_curvature: T,
+    pub dimensional_ratios: [T; 4],
+    pub temporal_evolution_rate: T,
 
-    pub dark_energy_density: f64,
-    pub dark_matter_coupling: f64,
+    pub dark_energy_density: T,
+    pub dark_matter_coupling: T,
+    #[serde(default = "default_dark_matter_mass")]
+    pub dark_matter_mass: T,
+    #[serde(default = "default_primordial_fluctuation_amplitude")]
+    pub primordial_fluctuation_amplitude: T,
+    #[serde(default = "default_baryon_to_photon_ratio")]
+    pub baryon_to_photon_ratio: T,
+    #[serde(default)]
+    pub higgs_sector: Option<HiggsSector<T>>,
+    #[serde(default = "default_neutrino_masses")]
+    pub neutrino_masses: [T; 3],
+    #[serde(default = "default_neutrino_species")]
+    pub neutrino_species: T,
+}
+
+// Parametrización alternativa: m_f = y_f v / √2 en lugar de masas independientes
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct HiggsSector<T = f64> {
+    pub higgs_vev: T, // GeV
+    pub yukawa_up: T,
+    pub yukawa_down: T,
+    pub yukawa_electron: T,
+    pub yukawa_charm: T,
+    pub yukawa_strange: T,
+    pub yukawa_muon: T,
+    pub yukawa_top: T,
+    pub yukawa_bottom: T,
+    pub yukawa_tauon: T,
+}
+
+impl<T: Real> HiggsSector<T> {
+    // Yukawas que reproducen las masas actuales con el vev dado
+    pub fn from_masses(laws: &CosmicLaw<T>, higgs_vev: T) -> Self {
+        let yukawa = |mass: T| T::from(2.0_f64.sqrt()) * mass * KG_TO_GEV / higgs_vev;
+        Self {
+            higgs_vev,
+            yukawa_up: yukawa(laws.mass_up_quark),
+            yukawa_down: yukawa(laws.mass_down_quark),
+            yukawa_electron: yukawa(laws.mass_electron),
+            yukawa_charm: yukawa(laws.mass_charm_quark),
+            yukawa_strange: yukawa(laws.mass_strange_quark),
+            yukawa_muon: yukawa(laws.mass_muon),
+            yukawa_top: yukawa(laws.mass_top_quark),
+            yukawa_bottom: yukawa(laws.mass_bottom_quark),
+            yukawa_tauon: yukawa(laws.mass_tauon),
+        }
+    }
+
+    fn fermion_mass(&self, yukawa: T) -> T {
+        yukawa * self.higgs_vev / 2.0_f64.sqrt() * GEV_TO_KG
+    }
+}
+
+impl<T: Real> CosmicLaw<T> {
+    // Si hay sector de Higgs, las masas de los fermiones salen de él
+    pub fn with_derived_masses(mut self) -> Self {
+        if let Some(higgs) = self.higgs_sector {
+            self.mass_up_quark = higgs.fermion_mass(higgs.yukawa_up);
+            self.mass_down_quark = higgs.fermion_mass(higgs.yukawa_down);
+            self.mass_electron = higgs.fermion_mass(higgs.yukawa_electron);
+            self.mass_charm_quark = higgs.fermion_mass(higgs.yukawa_charm);
+            self.mass_strange_quark = higgs.fermion_mass(higgs.yukawa_strange);
+            self.mass_muon = higgs.fermion_mass(higgs.yukawa_muon);
+            self.mass_top_quark = higgs.fermion_mass(higgs.yukawa_top);
+            self.mass_bottom_quark = higgs.fermion_mass(higgs.yukawa_bottom);
+            self.mass_tauon = higgs.fermion_mass(higgs.yukawa_tauon);
+        }
+        self
+    }
+}
+
+impl CosmicLaw {
+    // Nuestro universo: ancla de la validación y de las escalas solares del motor
+    pub fn reference() -> Self {
+        CosmicLaw {
+            g: G_GRAVITATIONAL,  // Usar constante de constants.rs
+            e: ELEMENTARY_CHARGE, // Usar constante exacta
+            alpha_s: 0.1181, // Valor a escala de la masa del Z. El engine lo hará "correr".
+            alpha_w: 0.03062,
+
+            mass_up_quark: 2.16 * MEV_TO_KG,
+            mass_down_quark: 4.67 * MEV_TO_KG,
+            mass_electron: ELECTRON_MASS_EXACT,
+
+            mass_strange_quark: 93.4 * MEV_TO_KG,
+            mass_charm_quark: 1.27 * KG_TO_GEV.recip(), // 1.27 GeV
+            mass_muon: 105.658 * MEV_TO_KG,
+
+            mass_bottom_quark: 4.18 * KG_TO_GEV.recip(), // 4.18 GeV
+            mass_top_quark: 172.76 * KG_TO_GEV.recip(), // 172.76 GeV
+            mass_tauon: 1776.86 * MEV_TO_KG, // 1.77686 GeV
+
+            spatial_curvature: 0.0007,
+            dimensional_ratios: [1.0, 1.0, 1.0, 1.0],
+            temporal_evolution_rate: 1.0,
+            dark_energy_density: 1.2e-29, // kg/m³
+            dark_matter_coupling: 0.26,
+            dark_matter_mass: 225.0 * KG_TO_GEV.recip(), // 225 GeV, Ω_dm ≈ 0.26 por congelamiento térmico
+            primordial_fluctuation_amplitude: 2.0e-5, // Q
+            baryon_to_photon_ratio: 6.1e-10, // η
+            higgs_sector: None,
+            neutrino_masses: [0.0, 0.0086 * EV_TO_KG, 0.05 * EV_TO_KG], // Jerarquía normal
+            neutrino_species: 3.0,
+        }
+    }
+
+    // Las mismas leyes sobre otro escalar (p. ej. números duales para derivar)
+pub fn lift<U: Real>(&self) -> CosmicLaw<U> {
+        CosmicLaw {
+            g: U::from(self.g),
+            e: U::from(self.e),
+            alpha_s: U::from(self.alpha_s),
+            alpha_w: U::from(self.alpha_w),
+            mass_up_quark: U::from(self.mass_up_quark),
+            mass_down_quark: U::from(self.mass_down_quark),
+            mass_electron: U::from(self.mass_electron),
+            mass_charm_quark: U::from(self.mass_charm_quark),
+            mass_strange_quark: U::from(self.mass_strange_quark),
+            mass_muon: U::from(self.mass_muon),
+            mass_top_quark: U::from(self.mass_top_quark),
+            mass_bottom_quark: U::from(self.mass_bottom_quark),
+            mass_tauon: U::from(self.mass_tauon),
+            spatial_curvature: U::from(self.spatial_curvature),
+            dimensional_ratios: self.dimensional_ratios.map(U::from),
+            temporal_evolution_rate: U::from(self.temporal_evolution_rate),
+            dark_energy_density: U::from(self.dark_energy_density),
+            dark_matter_coupling: U::from(self.dark_matter_coupling),
+            dark_matter_mass: U::from(self.dark_matter_mass),
+            primordial_fluctuation_amplitude: U::from(self.primordial_fluctuation_amplitude),
+            baryon_to_photon_ratio: U::from(self.baryon_to_photon_ratio),
+            higgs_sector: self.higgs_sector.map(|higgs| HiggsSector {
+                higgs_vev: U::from(higgs.higgs_vev),
+                yukawa_up: U::from(higgs.yukawa_up),
+                yukawa_down: U::from(higgs.yukawa_down),
+                yukawa_electron: U::from(higgs.yukawa_electron),
+                yukawa_charm: U::from(higgs.yukawa_charm),
+                yukawa_strange: U::from(higgs.yukawa_strange),
+                yukawa_muon: U::from(higgs.yukawa_muon),
+                yukawa_top: U::from(higgs.yukawa_top),
+                yukawa_bottom: U::from(higgs.yukawa_bottom),
+                yukawa_tauon: U::from(higgs.yukawa_tauon),
+            }),
+            neutrino_masses: self.neutrino_masses.map(U::from),
+            neutrino_species: U::from(self.neutrino_species),
+        }
+    }
+}
+
+fn default_dark_matter_mass<T: Real>() -> T {
+    T::from(225.0 * GEV_TO_KG)
+}
+
+fn default_primordial_fluctuation_amplitude<T: Real>() -> T {
+    T::from(2.0e-5)
+}
+
+fn default_baryon_to_photon_ratio<T: Real>() -> T {
+    T::from(6.1e-10)
+}
+
+// Jerarquía normal con el autoestado más ligero sin masa
+fn default_neutrino_masses<T: Real>() -> [T; 3] {
+    [0.0, 0.0086 * EV_TO_KG, 0.05 * EV_TO_KG].map(T::from)
+}
+
+fn default_neutrino_species<T: Real>() -> T {
+    T::from(3.0)
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -38,3 +207,99 @@ pub struct CosmicNarrative {
     pub finale_type: String,
     pub reproducibility: f64,
 }
+
+// Gated: umbrales duros como siempre; Smooth: cada umbral es una logística de nitidez dada
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FitnessModel {
+    #[default]
+    Gated,
+    Smooth { sharpness: f64 },
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FitnessBreakdown<T = f64> {
+    pub cosmology: T,
+    pub structure: T,
+    pub nucleosynthesis: T,
+    pub chemistry: T,
+    pub periodic_table: T,
+    pub stellar: T,
+    pub heavy_elements: T,
+    pub black_holes: T,
+    pub planets: T,
+    pub neutrino_species_bbn: T,
+    pub neutrino_suppression: T,
+    pub uncapped_fitness: T, // Antes del tope en 1: el universo de referencia llega a 1.05
+    pub fitness: T,
+    pub complexity_level: u8,
+    pub failure_reason: Option<String>,
+}
+
+// Niveles de complejidad que asignan los modelos de fitness: de Estéril (0) a Habitable (6)
+pub const COMPLEXITY_LEVELS: usize = 7;
+
+// Puntuaciones parciales que pueden tratarse como objetivos separados
+pub const FITNESS_COMPONENTS: [&str; 9] = [
+    "cosmology",
+    "structure",
+    "nucleosynthesis",
+    "chemistry",
+    "periodic_table",
+    "stellar",
+    "heavy_elements",
+    "black_holes",
+    "planets",
+];
+
+// Peso de cada componente en el fitness, en el orden de FITNESS_COMPONENTS; común a ambos modelos
+pub const FITNESS_WEIGHTS: [f64; 9] = [0.10, 0.10, 0.15, 0.10, 0.10, 0.15, 0.10, 0.10, 0.10];
+pub const COMPLEXITY_BONUS: f64 = 0.05; // Bonus pequeño al alcanzar el nivel 5
+
+impl<T: Real> FitnessBreakdown<T> {
+    pub fn component(&self, name: &str) -> Option<T> {
+        match name {
+            "cosmology" => Some(self.cosmology),
+            "structure" => Some(self.structure),
+            "nucleosynthesis" => Some(self.nucleosynthesis),
+            "chemistry" => Some(self.chemistry),
+            "periodic_table" => Some(self.periodic_table),
+            "stellar" => Some(self.stellar),
+            "heavy_elements" => Some(self.heavy_elements),
+            "black_holes" => Some(self.black_holes),
+            "planets" => Some(self.planets),
+            "fitness" => Some(self.fitness),
+            _ => None,
+        }
+    }
+
+    // Suma ponderada de las etapas, sin bonus ni tope
+    pub fn weighted_components(&self) -> T {
+        FITNESS_COMPONENTS
+            .iter()
+            .zip(FITNESS_WEIGHTS)
+            .map(|(name, weight)| self.component(name).unwrap_or(T::from(0.0)) * weight)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_marks_in_every_stage_weigh_one() {
+        let breakdown = FitnessBreakdown::<f64> {
+            cosmology: 1.0,
+            structure: 1.0,
+            nucleosynthesis: 1.0,
+            chemistry: 1.0,
+            periodic_table: 1.0,
+            stellar: 1.0,
+            heavy_elements: 1.0,
+            black_holes: 1.0,
+            planets: 1.0,
+            ..Default::default()
+        };
+        assert!((breakdown.weighted_components() - 1.0).abs() < 1e-12);
+    }
+}
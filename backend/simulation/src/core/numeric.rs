@@ -0,0 +1,385 @@
+use std::fmt::Debug;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Escalar sobre el que corre el motor: f64 para simular, Dual para derivar
+pub trait Real:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + PartialOrd
+    + PartialEq<f64>
+    + PartialOrd<f64>
+    + From<f64>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Add<f64, Output = Self>
+    + Sub<f64, Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + AddAssign<f64>
+    + MulAssign<f64>
+    + Sum
+    + Product
+{
+    fn value(self) -> f64;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn log10(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: f64) -> Self;
+    fn pow(self, exponent: Self) -> Self;
+    fn recip(self) -> Self;
+    fn tan(self) -> Self;
+    fn abs(self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn clamp(self, low: f64, high: f64) -> Self;
+
+    fn is_nan(self) -> bool {
+        self.value().is_nan()
+    }
+
+    fn is_finite(self) -> bool {
+        self.value().is_finite()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.value().is_infinite()
+    }
+}
+
+impl Real for f64 {
+    fn value(self) -> f64 {
+        self
+    }
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+    fn cbrt(self) -> Self {
+        f64::cbrt(self)
+    }
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+    fn log10(self) -> Self {
+        f64::log10(self)
+    }
+    fn powi(self, n: i32) -> Self {
+        f64::powi(self, n)
+    }
+    fn powf(self, n: f64) -> Self {
+        f64::powf(self, n)
+    }
+    fn pow(self, exponent: Self) -> Self {
+        f64::powf(self, exponent)
+    }
+    fn recip(self) -> Self {
+        f64::recip(self)
+    }
+    fn tan(self) -> Self {
+        f64::tan(self)
+    }
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+    fn max(self, other: Self) -> Self {
+        f64::max(self, other)
+    }
+    fn min(self, other: Self) -> Self {
+        f64::min(self, other)
+    }
+    fn clamp(self, low: f64, high: f64) -> Self {
+        f64::clamp(self, low, high)
+    }
+}
+
+// Número dual a + b ε con ε² = 0: la parte infinitesimal arrastra la derivada en modo directo
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Dual {
+    pub value: f64,
+    pub derivative: f64,
+}
+
+impl Dual {
+    pub fn constant(value: f64) -> Self {
+        Self { value, derivative: 0.0 }
+    }
+
+    // La variable respecto a la que se deriva
+    pub fn variable(value: f64) -> Self {
+        Self { value, derivative: 1.0 }
+    }
+
+    // Regla de la cadena: f(a + b ε) = f(a) + f'(a) b ε
+    fn chain(self, value: f64, slope: f64) -> Self {
+        // Una derivada nula multiplicada por una pendiente infinita sigue siendo nula
+        let derivative = if self.derivative == 0.0 { 0.0 } else { slope * self.derivative };
+        Self { value, derivative }
+    }
+}
+
+impl From<f64> for Dual {
+    fn from(value: f64) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl PartialEq<f64> for Dual {
+    fn eq(&self, other: &f64) -> bool {
+        self.value == *other
+    }
+}
+
+// Las comparaciones solo miran el valor: las ramas se eligen como en f64
+impl PartialOrd for Dual {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl PartialOrd<f64> for Dual {
+    fn partial_cmp(&self, other: &f64) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self { value: self.value + other.value, derivative: self.derivative + other.derivative }
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self { value: self.value - other.value, derivative: self.derivative - other.derivative }
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let derivative = match (self.derivative == 0.0, other.derivative == 0.0) {
+            (true, true) => 0.0,
+            (true, false) => self.value * other.derivative,
+            (false, true) => self.derivative * other.value,
+            (false, false) => self.derivative * other.value + self.value * other.derivative,
+        };
+        Self { value: self.value * other.value, derivative }
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        let value = self.value / other.value;
+        let derivative = match (self.derivative == 0.0, other.derivative == 0.0) {
+            (true, true) => 0.0,
+            (false, true) => self.derivative / other.value,
+            _ => (self.derivative - value * other.derivative) / other.value,
+        };
+        Self { value, derivative }
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { value: -self.value, derivative: -self.derivative }
+    }
+}
+
+impl Add<f64> for Dual {
+    type Output = Self;
+    fn add(self, other: f64) -> Self {
+        self + Dual::constant(other)
+    }
+}
+
+impl Sub<f64> for Dual {
+    type Output = Self;
+    fn sub(self, other: f64) -> Self {
+        self - Dual::constant(other)
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Self;
+    fn mul(self, other: f64) -> Self {
+        self * Dual::constant(other)
+    }
+}
+
+impl Div<f64> for Dual {
+    type Output = Self;
+    fn div(self, other: f64) -> Self {
+        self / Dual::constant(other)
+    }
+}
+
+impl AddAssign for Dual {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Dual {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Dual {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Dual {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl AddAssign<f64> for Dual {
+    fn add_assign(&mut self, other: f64) {
+        *self = *self + other;
+    }
+}
+
+impl MulAssign<f64> for Dual {
+    fn mul_assign(&mut self, other: f64) {
+        *self = *self * other;
+    }
+}
+
+impl Sum for Dual {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Dual::constant(0.0), |total, x| total + x)
+    }
+}
+
+impl Product for Dual {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Dual::constant(1.0), |total, x| total * x)
+    }
+}
+
+impl Real for Dual {
+    fn value(self) -> f64 {
+        self.value
+    }
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        self.chain(root, 0.5 / root)
+    }
+    fn cbrt(self) -> Self {
+        let root = self.value.cbrt();
+        self.chain(root, 1.0 / (3.0 * root * root))
+    }
+    fn exp(self) -> Self {
+        let value = self.value.exp();
+        self.chain(value, value)
+    }
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+    fn log10(self) -> Self {
+        self.chain(self.value.log10(), 1.0 / (self.value * std::f64::consts::LN_10))
+    }
+    fn powi(self, n: i32) -> Self {
+        self.chain(self.value.powi(n), n as f64 * self.value.powi(n - 1))
+    }
+    fn powf(self, n: f64) -> Self {
+        self.chain(self.value.powf(n), n * self.value.powf(n - 1.0))
+    }
+    fn pow(self, exponent: Self) -> Self {
+        let value = self.value.powf(exponent.value);
+        let base_term = if self.derivative == 0.0 { 0.0 } else { exponent.value * self.value.powf(exponent.value - 1.0) * self.derivative };
+        let exponent_term = if exponent.derivative == 0.0 { 0.0 } else { value * self.value.ln() * exponent.derivative };
+        Self { value, derivative: base_term + exponent_term }
+    }
+    fn recip(self) -> Self {
+        self.chain(self.value.recip(), -1.0 / (self.value * self.value))
+    }
+    fn tan(self) -> Self {
+        let cosine = self.value.cos();
+        self.chain(self.value.tan(), 1.0 / (cosine * cosine))
+    }
+    fn abs(self) -> Self {
+        if self.value < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+    fn max(self, other: Self) -> Self {
+        // Igual que f64::max: un NaN cede ante el otro operando
+        if self.value.is_nan() || other.value > self.value {
+            other
+        } else {
+            self
+        }
+    }
+    fn min(self, other: Self) -> Self {
+        if self.value.is_nan() || other.value < self.value {
+            other
+        } else {
+            self
+        }
+    }
+    fn clamp(self, low: f64, high: f64) -> Self {
+        if self.value < low {
+            Dual::constant(low)
+        } else if self.value > high {
+            Dual::constant(high)
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() <= 1e-12 * expected.abs().max(1.0), "{} vs {}", actual, expected);
+    }
+
+    #[test]
+    fn elementary_derivatives_match_analytic_values() {
+        let x = Dual::variable(2.5);
+        assert_close(x.powf(1.5).derivative, 1.5 * 2.5_f64.sqrt());
+        assert_close(x.exp().derivative, 2.5_f64.exp());
+        assert_close(x.sqrt().derivative, 0.5 / 2.5_f64.sqrt());
+        assert_close(x.ln().derivative, 1.0 / 2.5);
+        assert_close(x.cbrt().derivative, 2.5_f64.powf(-2.0 / 3.0) / 3.0);
+        assert_close(x.powi(3).derivative, 3.0 * 2.5 * 2.5);
+        assert_close(x.tan().derivative, 1.0 / 2.5_f64.cos().powi(2));
+    }
+
+    #[test]
+    fn product_and_quotient_rules() {
+        let x = Dual::variable(3.0);
+        assert_close((x * x.exp()).derivative, 4.0 * 3.0_f64.exp());
+        assert_close((Dual::constant(1.0) / x).derivative, -1.0 / 9.0);
+        assert_close(x.pow(x).derivative, 27.0 * (3.0_f64.ln() + 1.0));
+        assert_eq!(Dual::constant(3.0).exp().derivative, 0.0);
+    }
+}
@@ -0,0 +1,257 @@
+use crate::core::models::CosmicLaw;
+use crate::physics::constants::{EV_TO_KG, GEV_TO_KG, MEV_TO_KG};
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterScale {
+    Linear,
+    Logarithmic,
+}
+
+// Un campo escalar de CosmicLaw con su prior uniforme (en su escala)
+#[derive(Debug, Clone, Copy)]
+pub struct LawParameter {
+    pub name: &'static str,
+    pub scale: ParameterScale,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl LawParameter {
+    pub const fn linear(name: &'static str, min: f64, max: f64) -> Self {
+        Self { name, scale: ParameterScale::Linear, min, max }
+    }
+
+    pub const fn logarithmic(name: &'static str, min: f64, max: f64) -> Self {
+        Self { name, scale: ParameterScale::Logarithmic, min, max }
+    }
+
+    // Posición dentro del prior: 0 en el mínimo, 1 en el máximo
+    pub fn unit_position(&self, value: f64) -> f64 {
+        match self.scale {
+            ParameterScale::Linear => (value - self.min) / (self.max - self.min),
+            ParameterScale::Logarithmic => (value / self.min).ln() / (self.max / self.min).ln(),
+        }
+    }
+
+    pub fn value_at(&self, unit: f64) -> f64 {
+        match self.scale {
+            ParameterScale::Linear => self.min + unit * (self.max - self.min),
+            ParameterScale::Logarithmic => self.min * (self.max / self.min).powf(unit),
+        }
+    }
+
+    // Las cantidades lineales con prior no negativo (densidades, masas, razones) no pueden cruzar el cero
+    fn clamp(&self, value: f64) -> f64 {
+        if self.scale == ParameterScale::Linear && self.min >= 0.0 {
+            value.max(0.0)
+        } else {
+            value
+        }
+    }
+}
+
+// Las masas y los acoplamientos abarcan décadas alrededor de nuestro universo; el resto, los rangos de los muestreadores
+pub const LAW_PARAMETERS: [LawParameter; 28] = [
+    LawParameter::logarithmic("g", 6.674e-12, 6.674e-10),
+    LawParameter::linear("e", 0.5e-19, 2.5e-19),
+    LawParameter::logarithmic("alpha_s", 0.05, 2.0),
+    LawParameter::logarithmic("alpha_w", 3.0e-3, 0.3),
+    LawParameter::logarithmic("mass_up_quark", 0.216 * MEV_TO_KG, 21.6 * MEV_TO_KG),
+    LawParameter::logarithmic("mass_down_quark", 0.467 * MEV_TO_KG, 46.7 * MEV_TO_KG),
+    LawParameter::logarithmic("mass_electron", 0.0511 * MEV_TO_KG, 5.11 * MEV_TO_KG),
+    LawParameter::logarithmic("mass_charm_quark", 0.127 * GEV_TO_KG, 12.7 * GEV_TO_KG),
+    LawParameter::logarithmic("mass_strange_quark", 9.34 * MEV_TO_KG, 934.0 * MEV_TO_KG),
+    LawParameter::logarithmic("mass_muon", 10.57 * MEV_TO_KG, 1056.6 * MEV_TO_KG),
+    LawParameter::logarithmic("mass_top_quark", 17.28 * GEV_TO_KG, 1727.6 * GEV_TO_KG),
+    LawParameter::logarithmic("mass_bottom_quark", 0.418 * GEV_TO_KG, 41.8 * GEV_TO_KG),
+    LawParameter::logarithmic("mass_tauon", 0.1777 * GEV_TO_KG, 17.77 * GEV_TO_KG),
+    LawParameter::linear("spatial_curvature", -1.0, 1.0),
+    LawParameter::linear("dimensional_ratio_0", 0.5, 2.0),
+    LawParameter::linear("dimensional_ratio_1", 0.5, 2.0),
+    LawParameter::linear("dimensional_ratio_2", 0.5, 2.0),
+    LawParameter::linear("dimensional_ratio_3", 0.5, 2.0),
+    LawParameter::logarithmic("temporal_evolution_rate", 0.1, 10.0),
+    LawParameter::linear("dark_energy_density", 0.0, 4.0e-29),
+    LawParameter::linear("dark_matter_coupling", 0.0, 1.0),
+    LawParameter::logarithmic("dark_matter_mass", 10.0 * GEV_TO_KG, 1.0e4 * GEV_TO_KG),
+    LawParameter::logarithmic("primordial_fluctuation_amplitude", 1.0e-6, 1.0e-4),
+    LawParameter::logarithmic("baryon_to_photon_ratio", 1.0e-11, 1.0e-9),
+    LawParameter::linear("neutrino_mass_1", 0.0, 1.0 * EV_TO_KG),
+    LawParameter::linear("neutrino_mass_2", 0.0, 1.0 * EV_TO_KG),
+    LawParameter::linear("neutrino_mass_3", 0.0, 1.0 * EV_TO_KG),
+    LawParameter::linear("neutrino_species", 1.0, 6.0),
+];
+
+pub fn law_parameter(name: &str) -> Option<&'static LawParameter> {
+    LAW_PARAMETERS.iter().find(|parameter| parameter.name == name)
+}
+
+impl<T> CosmicLaw<T> {
+    pub fn parameter_mut(&mut self, name: &str) -> Option<&mut T> {
+        match name {
+            "g" => Some(&mut self.g),
+            "e" => Some(&mut self.e),
+            "alpha_s" => Some(&mut self.alpha_s),
+            "alpha_w" => Some(&mut self.alpha_w),
+            "mass_up_quark" => Some(&mut self.mass_up_quark),
+            "mass_down_quark" => Some(&mut self.mass_down_quark),
+            "mass_electron" => Some(&mut self.mass_electron),
+            "mass_charm_quark" => Some(&mut self.mass_charm_quark),
+            "mass_strange_quark" => Some(&mut self.mass_strange_quark),
+            "mass_muon" => Some(&mut self.mass_muon),
+            "mass_top_quark" => Some(&mut self.mass_top_quark),
+            "mass_bottom_quark" => Some(&mut self.mass_bottom_quark),
+            "mass_tauon" => Some(&mut self.mass_tauon),
+            "spatial_curvature" => Some(&mut self.spatial_curvature),
+            "dimensional_ratio_0" => Some(&mut self.dimensional_ratios[0]),
+            "dimensional_ratio_1" => Some(&mut self.dimensional_ratios[1]),
+            "dimensional_ratio_2" => Some(&mut self.dimensional_ratios[2]),
+            "dimensional_ratio_3" => Some(&mut self.dimensional_ratios[3]),
+            "temporal_evolution_rate" => Some(&mut self.temporal_evolution_rate),
+            "dark_energy_density" => Some(&mut self.dark_energy_density),
+            "dark_matter_coupling" => Some(&mut self.dark_matter_coupling),
+            "dark_matter_mass" => Some(&mut self.dark_matter_mass),
+            "primordial_fluctuation_amplitude" => Some(&mut self.primordial_fluctuation_amplitude),
+            "baryon_to_photon_ratio" => Some(&mut self.baryon_to_photon_ratio),
+            "neutrino_mass_1" => Some(&mut self.neutrino_masses[0]),
+            "neutrino_mass_2" => Some(&mut self.neutrino_masses[1]),
+            "neutrino_mass_3" => Some(&mut self.neutrino_masses[2]),
+            "neutrino_species" => Some(&mut self.neutrino_species),
+            _ => None,
+        }
+    }
+
+    pub fn parameter_ref(&self, name: &str) -> Option<&T> {
+        match name {
+            "g" => Some(&self.g),
+            "e" => Some(&self.e),
+            "alpha_s" => Some(&self.alpha_s),
+            "alpha_w" => Some(&self.alpha_w),
+            "mass_up_quark" => Some(&self.mass_up_quark),
+            "mass_down_quark" => Some(&self.mass_down_quark),
+            "mass_electron" => Some(&self.mass_electron),
+            "mass_charm_quark" => Some(&self.mass_charm_quark),
+            "mass_strange_quark" => Some(&self.mass_strange_quark),
+            "mass_muon" => Some(&self.mass_muon),
+            "mass_top_quark" => Some(&self.mass_top_quark),
+            "mass_bottom_quark" => Some(&self.mass_bottom_quark),
+            "mass_tauon" => Some(&self.mass_tauon),
+            "spatial_curvature" => Some(&self.spatial_curvature),
+            "dimensional_ratio_0" => Some(&self.dimensional_ratios[0]),
+            "dimensional_ratio_1" => Some(&self.dimensional_ratios[1]),
+            "dimensional_ratio_2" => Some(&self.dimensional_ratios[2]),
+            "dimensional_ratio_3" => Some(&self.dimensional_ratios[3]),
+            "temporal_evolution_rate" => Some(&self.temporal_evolution_rate),
+            "dark_energy_density" => Some(&self.dark_energy_density),
+            "dark_matter_coupling" => Some(&self.dark_matter_coupling),
+            "dark_matter_mass" => Some(&self.dark_matter_mass),
+            "primordial_fluctuation_amplitude" => Some(&self.primordial_fluctuation_amplitude),
+            "baryon_to_photon_ratio" => Some(&self.baryon_to_photon_ratio),
+            "neutrino_mass_1" => Some(&self.neutrino_masses[0]),
+            "neutrino_mass_2" => Some(&self.neutrino_masses[1]),
+            "neutrino_mass_3" => Some(&self.neutrino_masses[2]),
+            "neutrino_species" => Some(&self.neutrino_species),
+            _ => None,
+        }
+    }
+}
+
+impl CosmicLaw {
+    pub fn parameter(&self, name: &str) -> Option<f64> {
+        self.parameter_ref(name).copied()
+    }
+
+    pub fn set_parameter(&mut self, name: &str, value: f64) {
+        if let Some(slot) = self.parameter_mut(name) {
+            *slot = value;
+        }
+    }
+
+    // Coloca el campo en una posición de su prior, sin cruzar el cero si es una cantidad no negativa
+    pub fn set_unit_position(&mut self, parameter: &LawParameter, unit: f64) {
+        self.set_parameter(parameter.name, parameter.clamp(parameter.value_at(unit)));
+    }
+
+    // Paso gaussiano en la escala de cada campo, medido en anchos de prior
+    pub fn mutated(&self, strength: f64, rng: &mut impl Rng) -> CosmicLaw {
+        let mut child = self.clone();
+        let step = Normal::new(0.0, strength).expect("la intensidad de mutación debe ser finita y no negativa");
+        for parameter in &LAW_PARAMETERS {
+            if let Some(value) = self.parameter(parameter.name) {
+                child.set_unit_position(parameter, parameter.unit_position(value) + step.sample(rng));
+            }
+        }
+        child
+    }
+
+    // Cruce uniforme: cada campo escalar viene de uno de los dos progenitores
+    pub fn crossover(&self, other: &CosmicLaw, rng: &mut impl Rng) -> CosmicLaw {
+        let mut child = self.clone();
+        for parameter in &LAW_PARAMETERS {
+            if rng.gen_bool(0.5) {
+                if let Some(value) = other.parameter(parameter.name) {
+                    child.set_parameter(parameter.name, value);
+                }
+            }
+        }
+        child
+    }
+
+    // Todos los campos escalares al azar dentro del prior; el resto se hereda de la base
+    pub fn sample_prior(base: &CosmicLaw, rng: &mut impl Rng) -> CosmicLaw {
+        Self::sample_ranges(base, &LAW_PARAMETERS, rng)
+    }
+
+    // Solo los campos dados, cada uno uniforme en su rango; para modos que exploran más allá del prior
+    pub fn sample_ranges(base: &CosmicLaw, parameters: &[LawParameter], rng: &mut impl Rng) -> CosmicLaw {
+        let mut laws = base.clone();
+        for parameter in parameters {
+            laws.set_parameter(parameter.name, parameter.value_at(rng.gen()));
+        }
+        laws
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn unit_position_round_trips() {
+        for parameter in &LAW_PARAMETERS {
+            let value = parameter.value_at(0.3);
+            assert!((parameter.unit_position(value) - 0.3).abs() < 1e-9, "{}", parameter.name);
+        }
+    }
+
+    #[test]
+    fn every_prior_field_is_addressable() {
+        let laws = CosmicLaw::reference();
+        for parameter in &LAW_PARAMETERS {
+            assert!(laws.parameter(parameter.name).is_some(), "{}", parameter.name);
+        }
+        assert_eq!(laws.parameter("alpha_s"), Some(laws.alpha_s));
+        assert_eq!(laws.parameter("no-existe"), None);
+    }
+
+    #[test]
+    fn prior_samples_stay_in_range_and_zero_mutation_is_identity() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let reference = CosmicLaw::reference();
+        let sample = CosmicLaw::sample_prior(&reference, &mut rng);
+        for parameter in &LAW_PARAMETERS {
+            let unit = parameter.unit_position(sample.parameter(parameter.name).unwrap());
+            assert!((0.0..=1.0).contains(&unit), "{}: {}", parameter.name, unit);
+        }
+
+        let copy = sample.mutated(0.0, &mut rng);
+        for parameter in &LAW_PARAMETERS {
+            let (before, after) = (sample.parameter(parameter.name).unwrap(), copy.parameter(parameter.name).unwrap());
+            assert!((before - after).abs() <= 1e-12 * before.abs(), "{}", parameter.name);
+        }
+    }
+}
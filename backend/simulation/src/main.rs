@@ -15,6 +15,9 @@ use std::error::Error;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    // Sustituye los umbrales duros del fitness por logísticas con esta nitidez, en cualquier modo
+    #[arg(long, global = true, value_parser = positive_finite)]
+    smooth: Option<f64>,
 }
 
 #[derive(Subcommand)]
@@ -51,13 +54,151 @@ enum Commands {
         #[arg(short, long, default_value_t = 5000)]
         samples: u32,
     },
-    Validate,
+    Validate {
+        #[arg(short, long)]
+        universe: Option<String>,
+    },
+    Evaluate {
+        #[arg(short, long)]
+        universe: Option<String>,
+    },
     Stream,
+    VevScan {
+        #[arg(short, long, default_value_t = 200)]
+        steps: u32,
+        #[arg(long, default_value_t = 0.1)]
+        min_ratio: f64,
+        #[arg(long, default_value_t = 10.0)]
+        max_ratio: f64,
+    },
+    NaturalSelection {
+        #[arg(short, long, default_value_t = 200, value_parser = at_least_one)]
+        population: usize,
+        #[arg(short, long, default_value_t = 100)]
+        generations: u32,
+        #[arg(short, long, default_value_t = 0.02, value_parser = non_negative_finite)]
+        mutation: f64,
+        #[arg(short, long)]
+        universe: Option<String>,
+    },
+    Pareto {
+        #[arg(short, long, default_value_t = 100, value_parser = at_least_one)]
+        population: usize,
+        #[arg(short, long, default_value_t = 50)]
+        generations: u32,
+        #[arg(short, long, default_value_t = 0.02, value_parser = non_negative_finite)]
+        mutation: f64,
+        #[arg(short, long)]
+        objectives: Option<String>,
+        #[arg(short, long)]
+        universe: Option<String>,
+    },
+    MapElites {
+        #[arg(short, long, default_value_t = 10000)]
+        iterations: u32,
+        #[arg(long, default_value_t = 1000)]
+        initial: u32,
+        #[arg(short, long, default_value_t = 0.02, value_parser = non_negative_finite)]
+        mutation: f64,
+        #[arg(short, long, default_value_t = 30)]
+        bins: usize,
+        #[arg(short, long, default_value = "map_elites_archive.json")]
+        archive: String,
+        #[arg(short, long)]
+        resume: bool,
+    },
+    Optimize {
+        #[arg(short, long)]
+        universe: Option<String>,
+        #[arg(short, long, default_value = "cmaes")]
+        algorithm: String,
+        #[arg(short, long)]
+        fields: Option<String>,
+        #[arg(short, long)]
+        lock: Option<String>,
+        #[arg(short, long, default_value_t = 200)]
+        iterations: u32,
+        #[arg(short, long, default_value_t = 0.02, value_parser = positive_finite)]
+        step: f64,
+    },
+    Gradient {
+        #[arg(short, long)]
+        universe: Option<String>,
+    },
+    Sensitivity {
+        #[arg(short, long)]
+        universe: Option<String>,
+        #[arg(short, long, default_value_t = 1e-3, value_parser = unit_fraction)]
+        step: f64,
+        #[arg(short, long, default_value_t = 200, value_parser = at_least_one)]
+        points: usize,
+    },
+    Sobol {
+        #[arg(short, long)]
+        universe: Option<String>,
+        #[arg(short, long, default_value_t = 1024, value_parser = at_least_two)]
+        samples: usize,
+        #[arg(short, long, default_value_t = 200, value_parser = at_least_one)]
+        bootstrap: usize,
+    },
+}
+
+// Anchos de paso en unidades del prior: Normal::new no admite NaN, infinitos ni negativos
+fn non_negative_finite(value: &str) -> Result<f64, String> {
+    let parsed: f64 = value.parse().map_err(|_| format!("'{}' no es un número", value))?;
+    if parsed.is_finite() && parsed >= 0.0 {
+        Ok(parsed)
+    } else {
+        Err(format!("{} debe ser finito y no negativo", parsed))
+    }
+}
+
+fn positive_finite(value: &str) -> Result<f64, String> {
+    let parsed = non_negative_finite(value)?;
+    if parsed > 0.0 {
+        Ok(parsed)
+    } else {
+        Err("debe ser mayor que cero".to_string())
+    }
+}
+
+// Pasos relativos: con 1 o más, p·(1 − h) deja de ser positivo y el logaritmo no existe
+fn unit_fraction(value: &str) -> Result<f64, String> {
+    let parsed = positive_finite(value)?;
+    if parsed < 1.0 {
+        Ok(parsed)
+    } else {
+        Err("debe ser menor que 1".to_string())
+    }
+}
+
+// Tamaños de población y recuentos: con cero no hay campeón que indexar ni medias que dividir
+fn count_at_least(value: &str, minimum: usize) -> Result<usize, String> {
+    let parsed: usize = value.parse().map_err(|_| format!("'{}' no es un entero no negativo", value))?;
+    if parsed >= minimum {
+        Ok(parsed)
+    } else {
+        Err(format!("debe ser al menos {}", minimum))
+    }
+}
+
+fn at_least_one(value: &str) -> Result<usize, String> {
+    count_at_least(value, 1)
+}
+
+// Saltelli necesita dos filas para estimar una varianza
+fn at_least_two(value: &str) -> Result<usize, String> {
+    count_at_least(value, 2)
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
     let cli = Cli::parse();
 
+    if let Some(sharpness) = cli.smooth {
+        physics::engine::select_fitness_model(core::models::FitnessModel::Smooth { sharpness });
+        println!("🌊 Fitness suave: umbrales logísticos con nitidez {}", sharpness);
+    }
+
     match &cli.command {
         Commands::Map { universes } => {
         sim_modes::map::run_mapping_mode(*universes)?
@@ -74,8 +215,63 @@ fn main() -> Result<(), Box<dyn Error>> {
             samples,
         } => {sim_modes::alpha_fixed::run_alpha_fixed_mode(*alpha_denominator, *samples)?},
         Commands::Geometric { samples } => {sim_modes::geometric::run_geometric_mode(*samples)?},
-        Commands::Validate => sim_modes::validate::run_scientific_validation_mode()?,
+        Commands::Validate { universe } => sim_modes::validate::run_scientific_validation_mode(universe.as_deref())?,
+        Commands::Evaluate { universe } => sim_modes::evaluate::run_evaluation_mode(universe.as_deref())?,
         Commands::Stream => sim_modes::stream::run_streaming_mode()?,
+        Commands::VevScan {
+            steps,
+            min_ratio,
+            max_ratio,
+        } => sim_modes::vev_scan::run_vev_scan_mode(*steps, *min_ratio, *max_ratio)?,
+        Commands::NaturalSelection {
+            population,
+            generations,
+            mutation,
+            universe,
+        } => sim_modes::natural_selection::run_natural_selection_mode(*population, *generations, *mutation, universe.as_deref())?,
+        Commands::Pareto {
+            population,
+            generations,
+            mutation,
+            objectives,
+            universe,
+        } => sim_modes::pareto::run_pareto_mode(
+            *population,
+            *generations,
+            *mutation,
+            objectives.as_deref(),
+            universe.as_deref(),
+        )?,
+        Commands::MapElites {
+            iterations,
+            initial,
+            mutation,
+            bins,
+            archive,
+            resume,
+        } => sim_modes::map_elites::run_map_elites_mode(*iterations, *initial, *mutation, *bins, archive, *resume)?,
+        Commands::Optimize {
+            universe,
+            algorithm,
+            fields,
+            lock,
+            iterations,
+            step,
+        } => sim_modes::optimize::run_optimize_mode(
+            universe.as_deref(),
+            algorithm,
+            fields.as_deref(),
+            lock.as_deref(),
+            *iterations,
+            *step,
+        )?,
+        Commands::Gradient { universe } => sim_modes::gradient::run_gradient_mode(universe.as_deref())?,
+        Commands::Sensitivity { universe, step, points } => {
+            sim_modes::sensitivity::run_sensitivity_mode(universe.as_deref(), *step, *points)?
+        }
+        Commands::Sobol { universe, samples, bootstrap } => {
+            sim_modes::sobol::run_sobol_mode(universe.as_deref(), *samples, *bootstrap)?
+        }
     }
 
     Ok(())
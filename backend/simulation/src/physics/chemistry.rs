@@ -0,0 +1,100 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::engine::AdvancedPhysicsEngine;
+
+const H2_WELL_DEPTH_RYDBERG: f64 = 0.349; // D_e del H₂ en unidades de Rydberg
+const H2_ZERO_POINT_COEFFICIENT: f64 = 0.609; // ħω/2 = 0.609 Ry √(m_e/μ)
+const OPTIMAL_BOND_TO_STAR_RATIO: f64 = 9.0; // D₀ / kT☉ en nuestro universo
+const MOLECULAR_WINDOW_WIDTH: f64 = 3.0; // Factor tolerado en cada dirección
+const MIN_HYDROGEN_FRACTION: f64 = 0.5; // Por debajo no queda hidrógeno para agua y química orgánica
+
+#[derive(Debug, Clone, Copy)]
+pub struct Chemistry<T = f64> {
+    pub bohr_radius: T,                 // m
+    pub rydberg_energy: T,              // J
+    pub h2_bond_energy: T,              // J - D₀, descontada la energía de punto cero
+    pub stellar_surface_temperature: T, // K - estrella típica
+    pub molecular_window: T,            // D₀ / kT_superficie
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    pub fn bohr_radius(&self) -> T {
+        T::from(H_BAR) / (self.laws.mass_electron * C * self.alpha)
+    }
+
+    pub fn rydberg_energy(&self) -> T {
+        self.alpha.powi(2) * 0.5 * self.laws.mass_electron * C.powi(2)
+    }
+
+    // Born–Oppenheimer: el pozo escala con Ry y la vibración con √(m_e/m_p)
+    pub fn h2_bond_energy(&self) -> T {
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        if m_proton <= 0.0 {
+            return T::from(0.0);
+        }
+        let reduced_mass = m_proton / 2.0;
+        let zero_point = (self.laws.mass_electron / reduced_mass).sqrt() * H2_ZERO_POINT_COEFFICIENT;
+        (self.rydberg_energy() * (T::from(H2_WELL_DEPTH_RYDBERG) - zero_point)).max(T::from(0.0))
+    }
+
+    pub fn chemistry(&self) -> Chemistry<T> {
+        let h2_bond_energy = self.h2_bond_energy();
+        let stellar_surface_temperature = self.stellar_surface_temperature(self.typical_stellar_mass());
+        let molecular_window = if stellar_surface_temperature > 0.0 {
+            h2_bond_energy / (stellar_surface_temperature * K_B)
+        } else {
+            T::from(0.0)
+        };
+
+        Chemistry {
+            bohr_radius: self.bohr_radius(),
+            rydberg_energy: self.rydberg_energy(),
+            h2_bond_energy,
+            stellar_surface_temperature,
+            molecular_window,
+        }
+    }
+
+    // La luz estelar debe impulsar reacciones químicas sin disociar las moléculas
+    pub fn chemistry_score(&self) -> T {
+        let chemistry = self.chemistry();
+        if chemistry.h2_bond_energy <= 0.0 || chemistry.molecular_window <= 0.0 {
+            return T::from(0.0);
+        }
+
+        let log_offset = (chemistry.molecular_window / OPTIMAL_BOND_TO_STAR_RATIO).ln();
+        let window_score = (-log_offset.powi(2) / (2.0 * MOLECULAR_WINDOW_WIDTH.ln().powi(2))).exp();
+
+        let hydrogen_fraction = T::from(1.0) - self.thermal_history().primordial_helium_fraction;
+        let hydrogen_score = (hydrogen_fraction / MIN_HYDROGEN_FRACTION).clamp(0.0, 1.0);
+
+        window_score * hydrogen_score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    #[test]
+    fn reference_hydrogen_chemistry() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let chemistry = engine.chemistry();
+        assert!((chemistry.bohr_radius / 5.29177e-11 - 1.0).abs() < 1e-3);
+        // D₀(H₂) = 4.48 eV
+        let bond_ev = chemistry.h2_bond_energy / GEV_TO_JOULE * 1e9;
+        assert!((4.0..5.0).contains(&bond_ev), "D₀ = {} eV", bond_ev);
+        assert!(engine.chemistry_score() > 0.9);
+    }
+
+    #[test]
+    fn heavy_electrons_shake_molecules_apart() {
+        // Con m_e/m_p ≳ 0.16 la energía de punto cero supera el pozo del H₂
+        let mut laws = CosmicLaw::reference();
+        laws.mass_electron = 0.2 * PROTON_MASS_EMPIRICAL;
+        let engine = AdvancedPhysicsEngine::new(laws);
+        assert_eq!(engine.h2_bond_energy(), 0.0);
+        assert_eq!(engine.chemistry_score(), 0.0);
+    }
+}
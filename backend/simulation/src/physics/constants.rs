@@ -4,6 +4,7 @@ pub const EPSILON_0: f64 = 8.8541878128e-12; // Permitividad del vacío (F/m)
 pub const K_B: f64 = 1.380649e-23; // Constante de Boltzmann (J/K)
 pub const G_GRAVITATIONAL: f64 = 6.67430e-11; // Constante gravitacional (m³·kg⁻¹·s⁻²)
 pub const ELEMENTARY_CHARGE: f64 = 1.602176634e-19; // Carga elemental (C)
+pub const ALPHA_EMPIRICAL: f64 = 7.2973525693e-3; // Constante de estructura fina
 
 pub const ELECTRON_MASS_EXACT: f64 = 9.1093837015e-31; // kg
 pub const PROTON_MASS_EMPIRICAL: f64 = 1.67262192369e-27; // kg
@@ -11,11 +12,20 @@ pub const NEUTRON_MASS_EMPIRICAL: f64 = 1.67492749804e-27; // kg
 pub const PION_MASS_CHARGED: f64 = 2.48835417e-28; // kg (139.57 MeV)
 
 pub const LAMBDA_QCD_GEV: f64 = 0.217; // Escala de QCD (GeV)
+pub const W_BOSON_MASS_GEV: f64 = 80.379; // Masa del bosón W (GeV)
 pub const M_SOLAR: f64 = 1.98847e30; // Masa solar (kg)
+pub const SOLAR_SURFACE_TEMPERATURE: f64 = 5772.0; // K
+pub const SOLAR_LUMINOSITY: f64 = 3.828e26; // W
+pub const STEFAN_BOLTZMANN: f64 = 5.670374419e-8; // W·m⁻²·K⁻⁴
 pub const MPC_TO_METERS: f64 = 3.08567758e22; // Megaparsec a metros
+pub const HUBBLE_PARAMETER_H: f64 = 0.674; // H0 / (100 km/s/Mpc)
+pub const CMB_TEMPERATURE_TODAY: f64 = 2.7255; // K - define el "hoy" de cada universo
+pub const ZETA_3: f64 = 1.2020569; // ζ(3), densidad de fotones de cuerpo negro
 
 const JOULE_TO_EV: f64 = 6.242e18;
 pub const MEV_TO_JOULE: f64 = 1.602176634e-13;
 pub const GEV_TO_JOULE: f64 = 1.602176634e-10;
 pub const KG_TO_GEV: f64 = C * C / GEV_TO_JOULE;
-pub const MEV_TO_KG: f64 = MEV_TO_JOULE / (C * C);
\ No newline at end of file
+pub const MEV_TO_KG: f64 = MEV_TO_JOULE / (C * C);
+pub const GEV_TO_KG: f64 = GEV_TO_JOULE / (C * C);
+pub const EV_TO_KG: f64 = ELEMENTARY_CHARGE / (C * C);
\ No newline at end of file
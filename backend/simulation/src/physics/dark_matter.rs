@@ -0,0 +1,106 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::engine::AdvancedPhysicsEngine;
+use std::f64::consts::PI;
+
+const DM_DEGREES_OF_FREEDOM: f64 = 2.0; // Fermión de Majorana
+const G_STAR_FREEZE_OUT: f64 = 86.25; // Grados de libertad relativistas a T ~ GeV
+const MAX_RELIC_DENSITY: f64 = 1e6; // Tope para acoplamientos que nunca se aniquilan
+
+#[derive(Debug, Clone, Copy)]
+pub struct DarkMatterRelic<T = f64> {
+    pub annihilation_cross_section: T, // GeV⁻² - <σv> en onda s
+    pub omega_dm: T,                   // Ω_dm hoy
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    pub fn planck_mass_gev(&self) -> T {
+        (T::from(H_BAR * C) / self.laws.g).sqrt() * KG_TO_GEV
+    }
+
+    // Congelamiento térmico (Kolb & Turner): Ω h² ∝ x_f / (√g* M_pl <σv>)
+    pub fn dark_matter_relic(&self) -> DarkMatterRelic<T> {
+        let mass_gev = self.laws.dark_matter_mass * KG_TO_GEV;
+        let alpha_dm = self.laws.dark_matter_coupling.powi(2) / (4.0 * PI);
+        let planck_mass_gev = self.planck_mass_gev();
+
+        if mass_gev <= 0.0 || alpha_dm <= 0.0 || !planck_mass_gev.is_finite() {
+            return DarkMatterRelic {
+                annihilation_cross_section: T::from(0.0),
+                omega_dm: T::from(if mass_gev > 0.0 { MAX_RELIC_DENSITY } else { 0.0 }),
+            };
+        }
+
+        let sigma_v = alpha_dm.powi(2) * PI / mass_gev.powi(2);
+
+        let mut x_f = T::from(20.0);
+        for _ in 0..20 {
+            let argument = planck_mass_gev * (0.038 * DM_DEGREES_OF_FREEDOM) * mass_gev * sigma_v
+                / (x_f * G_STAR_FREEZE_OUT).sqrt();
+            x_f = argument.ln().max(T::from(1.0));
+        }
+
+        let omega_h2 = x_f * 1.07e9 / (planck_mass_gev * G_STAR_FREEZE_OUT.sqrt() * sigma_v);
+        let omega_dm = (omega_h2 / HUBBLE_PARAMETER_H.powi(2)).min(T::from(MAX_RELIC_DENSITY));
+
+        DarkMatterRelic {
+            annihilation_cross_section: sigma_v,
+            omega_dm,
+        }
+    }
+
+    pub fn critical_density(&self) -> T {
+        T::from(3.0 * hubble_rate_today().powi(2)) / (self.laws.g * (8.0 * PI))
+    }
+
+    // Ω_b = η n_γ m_p / ρ_crit con el fondo de fotones del CMB actual
+    pub fn baryon_density_parameter(&self) -> T {
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        let photon_density = 2.0 * ZETA_3 / PI.powi(2) * (K_B * CMB_TEMPERATURE_TODAY / (H_BAR * C)).powi(3);
+        self.laws.baryon_to_photon_ratio * photon_density * m_proton / self.critical_density()
+    }
+
+    pub fn matter_density_parameter(&self) -> T {
+        self.baryon_density_parameter() + self.dark_matter_relic().omega_dm + self.neutrino_density_parameter()
+    }
+
+    pub fn dark_energy_density_parameter(&self) -> T {
+        self.laws.dark_energy_density / self.critical_density()
+    }
+}
+
+pub fn hubble_rate_today() -> f64 {
+    HUBBLE_PARAMETER_H * 100e3 / MPC_TO_METERS // s⁻¹
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    #[test]
+    fn reference_wimp_freezes_out_near_observed_abundance() {
+        let relic = AdvancedPhysicsEngine::new(CosmicLaw::reference()).dark_matter_relic();
+        assert!((0.1..0.5).contains(&relic.omega_dm), "Ω_dm = {}", relic.omega_dm);
+    }
+
+    #[test]
+    fn stronger_annihilation_leaves_less_dark_matter() {
+        let reference = AdvancedPhysicsEngine::new(CosmicLaw::reference()).dark_matter_relic();
+        let mut laws = CosmicLaw::reference();
+        laws.dark_matter_coupling *= 2.0;
+        let stronger = AdvancedPhysicsEngine::new(laws).dark_matter_relic();
+
+        // <σv> ∝ g⁴ sube ×16; x_f crece solo logarítmicamente
+        assert!((stronger.annihilation_cross_section / reference.annihilation_cross_section - 16.0).abs() < 1e-9);
+        let suppression = reference.omega_dm / stronger.omega_dm;
+        assert!((10.0..16.0).contains(&suppression), "Ω_dm cae ×{}", suppression);
+    }
+
+    #[test]
+    fn massless_dark_matter_leaves_no_relic() {
+        let mut laws = CosmicLaw::reference();
+        laws.dark_matter_mass = 0.0;
+        assert_eq!(AdvancedPhysicsEngine::new(laws).dark_matter_relic().omega_dm, 0.0);
+    }
+}
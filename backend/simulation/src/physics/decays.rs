@@ -0,0 +1,234 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::engine::AdvancedPhysicsEngine;
+use std::collections::HashMap;
+use std::fmt;
+
+// Especies que deben sobrevivir para que exista química ordinaria
+const ESSENTIAL_SPECIES: [&str; 3] = ["p", "e⁻", "H"];
+
+#[derive(Debug, Clone, Copy)]
+pub struct Species<T = f64> {
+    pub name: &'static str,
+    pub mass: T, // kg
+    pub charge: i8,
+    pub baryon_number: i8,
+    pub lepton_flavour: [i8; 3], // (L_e, L_μ, L_τ)
+}
+
+#[derive(Debug, Clone)]
+pub struct DecayChannel {
+    pub parent: &'static str,
+    pub products: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DecayAnalysis {
+    pub stable: Vec<&'static str>,
+    pub channels: Vec<DecayChannel>,
+}
+
+impl fmt::Display for DecayChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} → {}", self.parent, self.products.join(" "))
+    }
+}
+
+impl<T> Species<T> {
+    fn new(name: &'static str, mass: T, charge: i8, baryon_number: i8, lepton_flavour: [i8; 3]) -> Self {
+        Self {
+            name,
+            mass,
+            charge,
+            baryon_number,
+            lepton_flavour,
+        }
+    }
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    pub fn particle_species(&self) -> Vec<Species<T>> {
+        let laws = &self.laws;
+        let (_, _, m_pion) = self.get_validated_hadron_masses();
+        // Sin mezcla: cada sabor toma la masa del autoestado del mismo índice, como en neutrinos.rs
+        let [neutrino_e, neutrino_mu, neutrino_tau] = laws.neutrino_masses;
+
+        const NONE: [i8; 3] = [0, 0, 0];
+        let mut species = vec![
+            Species::new("γ", T::from(0.0), 0, 0, NONE),
+            Species::new("νe", neutrino_e, 0, 0, [1, 0, 0]),
+            Species::new("ν̄e", neutrino_e, 0, 0, [-1, 0, 0]),
+            Species::new("νμ", neutrino_mu, 0, 0, [0, 1, 0]),
+            Species::new("ν̄μ", neutrino_mu, 0, 0, [0, -1, 0]),
+            Species::new("ντ", neutrino_tau, 0, 0, [0, 0, 1]),
+            Species::new("ν̄τ", neutrino_tau, 0, 0, [0, 0, -1]),
+            Species::new("e⁻", laws.mass_electron, -1, 0, [1, 0, 0]),
+            Species::new("e⁺", laws.mass_electron, 1, 0, [-1, 0, 0]),
+            Species::new("μ⁻", laws.mass_muon, -1, 0, [0, 1, 0]),
+            Species::new("μ⁺", laws.mass_muon, 1, 0, [0, -1, 0]),
+            Species::new("τ⁻", laws.mass_tauon, -1, 0, [0, 0, 1]),
+            Species::new("τ⁺", laws.mass_tauon, 1, 0, [0, 0, -1]),
+            Species::new("π⁺", m_pion, 1, 0, NONE),
+            Species::new("π⁻", m_pion, -1, 0, NONE),
+            Species::new("π⁰", m_pion, 0, 0, NONE),
+        ];
+
+        let spectrum = self.baryon_spectrum();
+        for baryon in &spectrum.baryons {
+            species.push(Species::new(baryon.name, baryon.mass, baryon.charge, 1, NONE));
+        }
+
+        // El átomo de hidrógeno cuenta aparte: la captura electrónica lo destruye aunque el protón sea estable
+        let hydrogen_mass = spectrum.mass_of("p") + laws.mass_electron - self.rydberg_energy() / C.powi(2);
+        species.push(Species::new("H", hydrogen_mass, 0, 1, [1, 0, 0]));
+
+        species
+    }
+
+    // Desintegraciones a dos y tres cuerpos que conservan carga, número bariónico y sabor leptónico
+    pub fn decay_channels(&self, parent: &Species<T>, species: &[Species<T>]) -> Vec<DecayChannel> {
+        let mut products: Vec<&Species<T>> = species
+            .iter()
+            .filter(|s| s.mass < parent.mass && s.name != "H")
+            .collect();
+        products.sort_by(|a, b| b.mass.value().total_cmp(&a.mass.value()));
+
+        conserving_combinations(parent, &products)
+            .into_iter()
+            .filter(|candidates| candidates.iter().map(|s| s.mass).sum::<T>() < parent.mass)
+            .map(|candidates| DecayChannel {
+                parent: parent.name,
+                products: candidates.iter().map(|s| s.name).collect(),
+            })
+            .collect()
+    }
+
+    pub fn decay_analysis(&self) -> DecayAnalysis {
+        let species = self.particle_species();
+        let mut stable = Vec::new();
+        let mut channels = Vec::new();
+
+        for parent in &species {
+            let parent_channels = self.decay_channels(parent, &species);
+            if parent_channels.is_empty() {
+                stable.push(parent.name);
+            }
+            channels.extend(parent_channels);
+        }
+
+        DecayAnalysis { stable, channels }
+    }
+
+    // Primer canal que destruye al protón, al electrón o al hidrógeno
+    pub fn essential_species_decay(&self) -> Option<DecayChannel> {
+        let species = self.particle_species();
+        species
+            .iter()
+            .filter(|s| ESSENTIAL_SPECIES.contains(&s.name))
+            .find_map(|parent| self.decay_channels(parent, &species).into_iter().next())
+    }
+
+    // MeV que le faltan al canal permitido más ligero de p, e⁻ o H; negativo si ya pueden desintegrarse
+    pub fn essential_decay_margin(&self) -> T {
+        let species = self.particle_species();
+        let mut margin = T::from(f64::INFINITY);
+        for parent in species.iter().filter(|s| ESSENTIAL_SPECIES.contains(&s.name)) {
+            let products: Vec<&Species<T>> = species
+                .iter()
+                .filter(|s| s.name != parent.name && s.name != "H")
+                .collect();
+            for candidates in conserving_combinations(parent, &products) {
+                // Ionizar el hidrógeno no es desintegrarlo
+                let ionization = parent.name == "H" && ["p", "e⁻"].iter().all(|name| candidates.iter().any(|s| s.name == *name));
+                if ionization {
+                    continue;
+                }
+                let excess = candidates.iter().map(|s| s.mass).sum::<T>() - parent.mass;
+                margin = margin.min(excess * C.powi(2) / MEV_TO_JOULE);
+            }
+        }
+        margin
+    }
+}
+
+// Combinaciones de dos y tres productos (con repetición) que conservan los números cuánticos del padre
+fn conserving_combinations<'a, T>(parent: &Species<T>, products: &[&'a Species<T>]) -> Vec<Vec<&'a Species<T>>> {
+    let lepton_flavour_conserved = |candidates: &[&Species<T>]| {
+        let lepton_flavour = candidates.iter().fold([0i8; 3], |total, s| {
+            [
+                total[0] + s.lepton_flavour[0],
+                total[1] + s.lepton_flavour[1],
+                total[2] + s.lepton_flavour[2],
+            ]
+        });
+        lepton_flavour == parent.lepton_flavour
+    };
+
+    // Fijados los dos primeros, carga y número bariónico determinan el tercero: solo se recorre su grupo
+    let mut by_charges: HashMap<(i8, i8), Vec<usize>> = HashMap::new();
+    for (index, product) in products.iter().enumerate() {
+        by_charges.entry((product.charge, product.baryon_number)).or_default().push(index);
+    }
+
+    let mut combinations = Vec::new();
+    for (i, &first) in products.iter().enumerate() {
+        for (j, &second) in products.iter().enumerate().skip(i) {
+            let missing_charge = parent.charge - first.charge - second.charge;
+            let missing_baryon_number = parent.baryon_number - first.baryon_number - second.baryon_number;
+            if missing_charge == 0 && missing_baryon_number == 0 && lepton_flavour_conserved(&[first, second]) {
+                combinations.push(vec![first, second]);
+            }
+            let Some(thirds) = by_charges.get(&(missing_charge, missing_baryon_number)) else {
+                continue;
+            };
+            for &k in thirds.iter().filter(|&&k| k >= j) {
+                let third = products[k];
+                if lepton_flavour_conserved(&[first, second, third]) {
+                    combinations.push(vec![first, second, third]);
+                }
+            }
+        }
+    }
+    combinations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    #[test]
+    fn reference_neutron_beta_decays_and_proton_survives() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let analysis = engine.decay_analysis();
+        assert!(analysis.stable.contains(&"p") && analysis.stable.contains(&"H"));
+        assert!(analysis.channels.iter().any(|c| c.to_string() == "n → p e⁻ ν̄e"));
+        assert!(engine.essential_species_decay().is_none());
+        assert!(engine.essential_decay_margin() > 0.0);
+    }
+
+    #[test]
+    fn channels_conserve_quantum_numbers() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let species = engine.particle_species();
+        let find = |name: &str| species.iter().find(|s| s.name == name).unwrap();
+        for channel in engine.decay_analysis().channels {
+            let parent = find(channel.parent);
+            let products: Vec<_> = channel.products.iter().map(|name| find(name)).collect();
+            assert_eq!(products.iter().map(|s| s.charge).sum::<i8>(), parent.charge, "{}", channel);
+            assert_eq!(products.iter().map(|s| s.baryon_number).sum::<i8>(), parent.baryon_number, "{}", channel);
+            assert!(products.iter().map(|s| s.mass).sum::<f64>() < parent.mass, "{}", channel);
+        }
+    }
+
+    #[test]
+    fn heavy_electron_neutrino_closes_beta_decay() {
+        let mut laws = CosmicLaw::reference();
+        laws.neutrino_masses[0] = 2.0 * MEV_TO_KG;
+        let engine = AdvancedPhysicsEngine::new(laws);
+        let species = engine.particle_species();
+        let antineutrino = species.iter().find(|s| s.name == "ν̄e").unwrap();
+        assert_eq!(antineutrino.mass, 2.0 * MEV_TO_KG);
+        assert!(engine.decay_analysis().channels.iter().all(|c| c.to_string() != "n → p e⁻ ν̄e"));
+    }
+}
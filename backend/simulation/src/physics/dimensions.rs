@@ -0,0 +1,129 @@
+use crate::core::models::CosmicLaw;
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::engine::AdvancedPhysicsEngine;
+
+// ln(R / ℓ_P) = (r / r_a) ln(a₀ / ℓ_P): r cuenta fracciones de la jerarquía Planck–átomo en e-pliegues.
+// r = 1 (nuestro universo) queda a medio camino, ~10⁻²¹ m, y r_a = 1.9 llega a a₀ dentro del prior [0.5, 2]:
+// la franja superior del prior abre una dimensión a los átomos; G de Kaluza–Klein pide r ≳ 3.4 y las órbitas r ≳ 3.6
+const ATOMIC_BREAKDOWN_RATIO: f64 = 1.9;
+const BOHR_RADIUS_REFERENCE: f64 = 5.29177210903e-11; // m - escala atómica
+const ORBITAL_SCALE: f64 = 1.496e11; // m - una unidad astronómica
+const STELLAR_SCALE: f64 = 6.957e8; // m - radio solar
+const KALUZA_KLEIN_TOWER_STRENGTH: f64 = 8.0 / 3.0; // α de Yukawa por dimensión toroidal (Kehagias y Sfetsos, 2000)
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExtraDimensions<T = f64> {
+    pub compactification_radii: [T; 4], // m
+    pub large_dimensions: u8,             // Dimensiones espaciales que ven los átomos
+    pub atoms_stable: bool,
+    pub orbits_stable: bool,
+    pub gravity_enhancement: T, // G_eff / G a escala estelar
+}
+
+impl<T: Real> ExtraDimensions<T> {
+    // Décadas entre la dimensión compacta más grande y la escala atómica; negativo si los átomos ya la ven
+    pub fn atomic_margin(&self) -> T {
+        scale_margin(&self.compactification_radii, BOHR_RADIUS_REFERENCE)
+    }
+
+    pub fn orbital_margin(&self) -> T {
+        scale_margin(&self.compactification_radii, ORBITAL_SCALE)
+    }
+}
+
+fn scale_margin<T: Real>(radii: &[T; 4], distance: f64) -> T {
+    let largest = radii.iter().copied().fold(T::from(0.0), T::max);
+    (T::from(distance) / largest).log10()
+}
+
+pub fn compactification_radii<T: Real>(laws: &CosmicLaw<T>) -> [T; 4] {
+    let planck_length = (laws.g * H_BAR / C.powi(3)).sqrt();
+    let hierarchy = (T::from(BOHR_RADIUS_REFERENCE) / planck_length).ln() / ATOMIC_BREAKDOWN_RATIO;
+    laws.dimensional_ratios
+        .map(|ratio| planck_length * (ratio.max(T::from(0.0)) * hierarchy).min(T::from(700.0)).exp())
+}
+
+// Las dimensiones extra más grandes que la distancia se comportan como abiertas
+pub fn open_dimensions_at<T: Real>(radii: &[T; 4], distance: f64) -> u8 {
+    3 + radii.iter().filter(|&&radius| radius > distance).count() as u8
+}
+
+// Torre de Kaluza–Klein: Yukawa α e^(−λ/R) si R < λ, ley de potencias de 4+n dimensiones si R > λ.
+// Los límites exactos solo valen lejos de R ~ λ; ahí se empalman por continuidad, sin constante libre
+fn kaluza_klein_enhancement<T: Real>(radii: &[T; 4], distance: f64) -> T {
+    let crossover = 1.0 + KALUZA_KLEIN_TOWER_STRENGTH * (-1.0_f64).exp(); // Valor de la Yukawa en R = λ
+    radii
+        .iter()
+        .map(|&radius| {
+            if radius < distance {
+                T::from(1.0) + (T::from(-distance) / radius).exp() * KALUZA_KLEIN_TOWER_STRENGTH
+            } else {
+                radius * crossover / distance
+            }
+        })
+        .product()
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    // Ehrenfest: con fuerzas 1/r^(n−1) solo n = 3 admite órbitas y átomos estables
+    pub fn extra_dimensions(&self) -> ExtraDimensions<T> {
+        let radii = self.compactification_radii;
+        let atomic_dimensions = open_dimensions_at(&radii, BOHR_RADIUS_REFERENCE);
+        let orbital_dimensions = open_dimensions_at(&radii, ORBITAL_SCALE);
+
+        ExtraDimensions {
+            compactification_radii: radii,
+            large_dimensions: atomic_dimensions,
+            atoms_stable: atomic_dimensions == 3,
+            orbits_stable: orbital_dimensions == 3,
+            gravity_enhancement: stellar_gravity_enhancement(&radii),
+        }
+    }
+}
+
+// G observado a escala estelar tras sumar la torre de Kaluza–Klein
+pub fn stellar_gravity_enhancement<T: Real>(radii: &[T; 4]) -> T {
+    kaluza_klein_enhancement(radii, STELLAR_SCALE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn breakdown_ratio_reaches_atomic_scale() {
+        let mut laws = CosmicLaw::reference();
+        laws.dimensional_ratios = [1.0, 1.0, 1.0, ATOMIC_BREAKDOWN_RATIO];
+        let radii = compactification_radii(&laws);
+        assert!(radii[0] > 1e-23 && radii[0] < 1e-20, "{:e}", radii[0]);
+        assert!((radii[3] / BOHR_RADIUS_REFERENCE - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn prior_contains_universes_with_open_dimensions() {
+        let reference = CosmicLaw::reference();
+        let mut rng = StdRng::seed_from_u64(11);
+        let unstable = (0..200)
+            .filter(|_| !AdvancedPhysicsEngine::new(CosmicLaw::sample_prior(&reference, &mut rng)).extra_dimensions().atoms_stable)
+            .count();
+        assert!(unstable > 0 && unstable < 100, "{} de 200 con átomos inestables", unstable);
+    }
+
+    #[test]
+    fn open_dimension_breaks_atoms_and_strengthens_gravity() {
+        let mut laws = CosmicLaw::reference();
+        laws.dimensional_ratios[0] = 5.0;
+        let dimensions = AdvancedPhysicsEngine::new(laws).extra_dimensions();
+        assert_eq!(dimensions.large_dimensions, 4);
+        assert!(!dimensions.atoms_stable);
+        assert!(dimensions.gravity_enhancement > 1.0);
+
+        let reference = AdvancedPhysicsEngine::new(CosmicLaw::reference()).extra_dimensions();
+        assert!(reference.atoms_stable && reference.orbits_stable);
+        assert_eq!(reference.gravity_enhancement, 1.0);
+    }
+}
@@ -1,31 +1,44 @@
-use crate::core::models::CosmicLaw;
+use crate::core::models::{CosmicLaw, FitnessBreakdown, FitnessModel, COMPLEXITY_BONUS};
+use crate::core::numeric::Real;
 use crate::physics::constants::*;
+use crate::physics::dimensions::{compactification_radii, stellar_gravity_enhancement};
+use crate::physics::smooth_fitness::smooth_fitness_breakdown;
 use std::f64::consts::PI;
+use std::sync::OnceLock;
+
+const NUCLEON_EM_SPLITTING_MEV: f64 = 1.342; // Calibrado en nuestro universo con m_n − m_p = 1.293 MeV: esa diferencia no es una predicción
+const CHIRAL_CONDENSATE_SCALE: f64 = 1.333; // |⟨q̄q⟩|^(1/3) / Λ_QCD
+const PION_DECAY_CONSTANT_GEV: f64 = 0.0921;
+const HOYLE_CONTACT_RADIUS_RANGES: f64 = 7.07; // 10 fm en alcances del pion
+const ETA_BURNING_THRESHOLD: f64 = 1e-11; // Bajo este η el deuterio no llega a helio
+const MIN_PRIMORDIAL_HELIUM: f64 = 0.1; // Sin neutrones supervivientes la BBN no produce helio
+const HYDROGEN_BURNING_FRACTION: f64 = 0.08; // Masa mínima de la secuencia principal en unidades de la escala estelar
+
+// Modelo de fitness elegido una vez por proceso desde la línea de comandos
+static FITNESS_MODEL: OnceLock<FitnessModel> = OnceLock::new();
+
+// Masa del protón que el propio modelo hadrónico da a nuestro universo: ancla de las escalas solares
+pub fn reference_proton_mass() -> f64 {
+    static REFERENCE_PROTON_MASS: OnceLock<f64> = OnceLock::new();
+    *REFERENCE_PROTON_MASS.get_or_init(|| AdvancedPhysicsEngine::new(CosmicLaw::reference()).get_validated_hadron_masses().0)
+}
 
-const KEV_TO_JOULE: f64 = 1.602176634e-16;
-const MEV_TO_JOULE: f64 = 1.602176634e-13;
-const GEV_TO_JOULE: f64 = 1.602176634e-10;
-
-const KG_TO_GEV: f64 = C * C / GEV_TO_JOULE;
-const GEV_TO_KG: f64 = GEV_TO_JOULE / (C * C);
-const MEV_TO_KG: f64 = MEV_TO_JOULE / (C * C);
-
-const PROTON_MASS_EMPIRICAL: f64 = 1.67262192369e-27;
-const NEUTRON_MASS_EMPIRICAL: f64 = 1.67492749804e-27;
-const ELECTRON_MASS_EXACT: f64 = 9.1093837015e-31;
-const PION_MASS_CHARGED: f64 = 2.48835417e-28; 
-
-const LAMBDA_QCD_GEV: f64 = 0.217; 
-
-pub struct AdvancedPhysicsEngine {
-    pub laws: CosmicLaw,
-    pub alpha: f64,
-    pub alpha_s_reference: f64,
+// Genérico en el escalar: f64 para simular, números duales para derivar
+pub struct AdvancedPhysicsEngine<T = f64> {
+    pub laws: CosmicLaw<T>,
+    pub alpha: T,
+    pub alpha_s_reference: T,
     pub reference_scale: f64, 
+    pub compactification_radii: [T; 4], // m
+    pub fitness_model: FitnessModel,
 }
 
-impl AdvancedPhysicsEngine {
-    pub fn new(laws: CosmicLaw) -> Self {
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    pub fn new(laws: CosmicLaw<T>) -> Self {
+        let mut laws = laws.with_derived_masses();
+        // El radio de compactificación se fija con el G fundamental, antes del desplazamiento KK
+        let compactification_radii = compactification_radii(&laws);
+        laws.g *= stellar_gravity_enhancement(&compactification_radii);
         let alpha = laws.e.powi(2) / (4.0 * PI * EPSILON_0 * H_BAR * C);
 
         Self {
@@ -33,10 +46,28 @@ impl AdvancedPhysicsEngine {
             alpha,
             alpha_s_reference: laws.alpha_s,
             reference_scale: 91.1876,  
+            compactification_radii,
+            fitness_model: FitnessModel::Gated,
+        }
+    }
+
+    pub fn with_fitness_model(mut self, fitness_model: FitnessModel) -> Self {
+        self.fitness_model = fitness_model;
+        self
+    }
+
+    // Umbral sobre un margen adimensional (positivo = se cumple): escalón o logística
+    pub fn gate(&self, margin: T) -> T {
+        if margin.is_nan() {
+            return T::from(0.0);
+        }
+        match self.fitness_model {
+            FitnessModel::Gated => T::from(if margin > 0.0 { 1.0 } else { 0.0 }),
+            FitnessModel::Smooth { sharpness } => T::from(1.0) / (T::from(1.0) + (-margin * sharpness).exp()),
         }
     }
 
-    pub fn running_alpha_s(&self, mu_gev: f64) -> f64 {
+    pub fn running_alpha_s(&self, mu_gev: f64) -> T {
         let lambda_qcd_gev = LAMBDA_QCD_GEV;
         
         if mu_gev <= lambda_qcd_gev * 1.5 {
@@ -47,7 +78,7 @@ impl AdvancedPhysicsEngine {
         let beta_0 = (11.0 * 3.0 - 2.0 * nf) / 3.0;
         
         let log_ratio = (mu_gev / self.reference_scale).ln();
-        let denominator = 1.0 + (beta_0 * self.alpha_s_reference / (2.0 * PI)) * log_ratio;
+        let denominator = T::from(1.0) + (self.alpha_s_reference * beta_0 / (2.0 * PI)) * log_ratio;
         
         if denominator > 0.1 {
             self.alpha_s_reference / denominator
@@ -56,50 +87,65 @@ impl AdvancedPhysicsEngine {
         }
     }
     
-    pub fn constituent_quark_mass(&self, bare_mass_kg: f64, flavor: &str) -> f64 {
+    pub fn constituent_quark_mass(&self, bare_mass_kg: T, flavor: &str) -> T {
         let bare_mass_gev = bare_mass_kg * KG_TO_GEV;
         
         let mu_hadronic = 1.0;
         let alpha_s_had = self.running_alpha_s(mu_hadronic);
 
-        let up_down_asymmetry = 0.5;
-        
         match flavor {
-            "up" => {
-            let chiral_mass_gev = 0.310;
-            let running_correction = 1.0 + 0.1 * alpha_s_had;
-            (chiral_mass_gev * running_correction) * GEV_TO_KG
-        },
-        "down" => {
-            let chiral_mass_gev = 0.310 + (0.0025 * up_down_asymmetry); 
-            let running_correction = 1.0 + 0.1 * alpha_s_had;
+            "up" | "down" => {
+            // La masa desnuda separa u de d; escala con el vev de Higgs
+            let chiral_mass_gev = T::from(0.310) + bare_mass_gev;
+            let running_correction = T::from(1.0) + alpha_s_had * 0.1;
             (chiral_mass_gev * running_correction) * GEV_TO_KG
         },
             "strange" => {
-                let base_mass_gev = 0.48;
-                let condensate_contribution = 0.1;
-                ((base_mass_gev + condensate_contribution) * (1.0 + 0.05 * alpha_s_had)) * GEV_TO_KG
+                // Misma masa quiral que u y d más la desnuda: m_s − m_u ≈ 175 MeV en nuestro universo
+                let base_mass_gev = T::from(0.310) + bare_mass_gev;
+                let condensate_contribution = 0.075;
+                ((base_mass_gev + condensate_contribution) * (T::from(1.0) + alpha_s_had * 0.1)) * GEV_TO_KG
             },
             "charm" => {
-                let mass_gev = bare_mass_gev.max(1.27);
-                (mass_gev * (1.0 + 0.02 * alpha_s_had)) * GEV_TO_KG
+                let mass_gev = bare_mass_gev.max(T::from(1.27));
+                (mass_gev * (T::from(1.0) + alpha_s_had * 0.02)) * GEV_TO_KG
             },
             _ => {
-                bare_mass_kg * (1.0 + 0.01 * alpha_s_had)
+                bare_mass_kg * (T::from(1.0) + alpha_s_had * 0.01)
             }
         }
     }
 
-    pub fn get_empirical_hadron_masses(&self) -> (f64, f64, f64) {
-        (PROTON_MASS_EMPIRICAL, NEUTRON_MASS_EMPIRICAL, PION_MASS_CHARGED)
+    // Gell-Mann–Oakes–Renner: m_π² f_π² = (m_u + m_d) |⟨q̄q⟩|, con ⟨q̄q⟩ ∝ Λ_QCD³
+    pub fn pion_mass(&self) -> T {
+        let light_quark_mass_gev = (self.laws.mass_up_quark + self.laws.mass_down_quark) * KG_TO_GEV;
+        if light_quark_mass_gev <= 0.0 {
+            return T::from(0.0);
+        }
+
+        let condensate_gev3 = (self.lambda_qcd_gev() * CHIRAL_CONDENSATE_SCALE).powi(3);
+        (light_quark_mass_gev * condensate_gev3).sqrt() / PION_DECAY_CONSTANT_GEV * GEV_TO_KG
+    }
+
+    // Alcance de Yukawa de la fuerza nuclear: longitud de Compton del pion
+    pub fn nuclear_force_range(&self) -> T {
+        let (_, _, m_pion) = self.get_validated_hadron_masses();
+        if m_pion <= 0.0 {
+            return T::from(0.0);
+        }
+        T::from(H_BAR) / (m_pion * C)
+    }
+
+    pub fn get_empirical_hadron_masses(&self) -> (T, T, T) {
+        (T::from(PROTON_MASS_EMPIRICAL), T::from(NEUTRON_MASS_EMPIRICAL), T::from(PION_MASS_CHARGED))
     }
-    pub fn realistic_qcd_binding(&self, _hadron_type: &str) -> f64 {
+    pub fn realistic_qcd_binding(&self, _hadron_type: &str) -> T {
         let mu_hadronic = 1.0;
         let alpha_s_had = self.running_alpha_s(mu_hadronic);
 
         let base_binding_gev = LAMBDA_QCD_GEV * 2.0;
 
-        let binding_gev = base_binding_gev * (1.0 + (alpha_s_had - 0.3) * 0.5);
+        let binding_gev = (T::from(1.0) + (alpha_s_had - 0.3) * 0.5) * base_binding_gev;
 
         binding_gev * GEV_TO_JOULE
     }
@@ -159,32 +205,34 @@ impl AdvancedPhysicsEngine {
         bag_constant_si * bag_volume
     }
     
-    pub fn get_theoretical_hadron_masses(&self) -> (f64, f64, f64) {
+    pub fn get_theoretical_hadron_masses(&self) -> (T, T, T) {
         let up_const = self.constituent_quark_mass(self.laws.mass_up_quark, "up");
         let down_const = self.constituent_quark_mass(self.laws.mass_down_quark, "down");
         
         let proton_binding_j = self.realistic_qcd_binding("proton");
         let neutron_binding_j = self.realistic_qcd_binding("neutron");
-        let pion_binding_j = self.realistic_qcd_binding("pion_charged");
 
         let proton_binding_mass = proton_binding_j / C.powi(2);
         let neutron_binding_mass = neutron_binding_j / C.powi(2);
-        let pion_binding_mass = pion_binding_j / C.powi(2);
 
-        let mass_proton = 2.0 * up_const + down_const - proton_binding_mass;
-        let mass_neutron = up_const + 2.0 * down_const - neutron_binding_mass;
-        let mass_pion = up_const + down_const - pion_binding_mass;
+        // Autoenergía electromagnética: el protón cargado es más pesado
+        let electromagnetic_splitting = self.alpha * NUCLEON_EM_SPLITTING_MEV / ALPHA_EMPIRICAL * MEV_TO_KG;
+
+        let mass_proton = up_const * 2.0 + down_const - proton_binding_mass + electromagnetic_splitting;
+        let mass_neutron = up_const + down_const * 2.0 - neutron_binding_mass;
+        let mass_pion = self.pion_mass();
         
-        (mass_proton.max(0.0), mass_neutron.max(0.0), mass_pion.max(0.0))
+        (mass_proton.max(T::from(0.0)), mass_neutron.max(T::from(0.0)), mass_pion.max(T::from(0.0)))
     }
 
-    pub fn get_validated_hadron_masses(&self) -> (f64, f64, f64) {
+    pub fn get_validated_hadron_masses(&self) -> (T, T, T) {
         if cfg!(feature = "empirical_validation") {
             self.get_empirical_hadron_masses()
         } else {
             let theoretical = self.get_theoretical_hadron_masses();
             let (m_p, m_n, _) = theoretical;
-            if m_p <= 0.0 || m_n <= m_p || m_p > 2e-26 || m_n > 2e-26 {
+            // m_n < m_p es un resultado físico (universo de neutrones), no un fallo del modelo
+            if m_p <= 0.0 || m_n <= 0.0 || m_p > 2e-26 || m_n > 2e-26 {
                 self.get_empirical_hadron_masses()
             } else {
                 theoretical
@@ -192,147 +240,224 @@ impl AdvancedPhysicsEngine {
         }
     }
 
-    pub fn cosmological_viability(&self) -> f64 {
+    pub fn cosmological_viability(&self) -> T {
     
     let density_approx = 1e-26; // Densidad aproximada tras la inflación
-    let freefall_time = (1.0 / (self.laws.g * density_approx)).sqrt();
+    let freefall_time = (T::from(1.0) / (self.laws.g * density_approx)).sqrt();
     
     if freefall_time < (1e8 * 31557600.0) {
-        return 0.0;
+        return T::from(0.0);
     }
 
-    let hubble_time_approx = (3.0 / (8.0 * PI * self.laws.g * self.laws.dark_energy_density)).sqrt();
-    
-    if hubble_time_approx < (1e8 * 31557600.0) {
-        return 0.0;
-    }
-    
-    1.0
+    // Demasiada materia o curvatura positiva cierran el universo antes de que evolucione
+    let complexity_time = 1e10 * 31557600.0;
+    T::from(1.0) - (-self.recollapse_time() / complexity_time).exp()
 }
-    pub fn nuclear_cross_section(&self, reaction_type: &str) -> f64 {
+    // Saha: el deuterio sobrevive a la fotodisociación cuando 6.5 η (kT/m_p c²)^(3/2) e^(B_d/kT) ~ 1
+    pub fn deuterium_bottleneck_temperature(&self) -> T {
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        let eta = self.laws.baryon_to_photon_ratio;
+        let deuteron_binding = self.light_nuclei().deuteron_binding;
+        if eta <= 0.0 || m_proton <= 0.0 || deuteron_binding <= 0.0 {
+            return T::from(0.0);
+        }
+
+        let binding_energy = deuteron_binding * MEV_TO_JOULE;
+        let proton_rest_energy = m_proton * C.powi(2);
+        let mut thermal_energy = binding_energy / 30.0;
+        for _ in 0..20 {
+            let log_ratio = (T::from(1.0) / (eta * 6.5)).ln() + (proton_rest_energy / thermal_energy).ln() * 1.5;
+            thermal_energy = binding_energy / log_ratio.max(T::from(1.0));
+        }
+
+        thermal_energy / K_B
+    }
+
+    pub fn nuclear_cross_section(&self, reaction_type: &str) -> T {
         let (m_proton, _, _) = self.get_validated_hadron_masses();
-        let thermal_energy = K_B * 1e9; // BBN temperature ~1 GK
+        let thermal_energy = self.deuterium_bottleneck_temperature() * K_B; // BBN ~0.07 MeV en nuestro universo
+        if thermal_energy <= 0.0 {
+            return T::from(0.0);
+        }
 
         match reaction_type {
             "p_p_fusion" => {
                 let reduced_mass = m_proton / 2.0;
-                let gamow_energy = 2.0 * self.alpha * (reduced_mass * thermal_energy / 2.0).sqrt();
+                let gamow_energy = self.alpha * 2.0 * (reduced_mass * thermal_energy / 2.0).sqrt();
                 let tunnel_probability = (-gamow_energy / thermal_energy).exp();
                 tunnel_probability * 1e-45
             }
             "d_p_fusion" => {
                 let deuteron_mass = m_proton * 2.0; // Aproximación
                 let reduced_mass = m_proton * deuteron_mass / (m_proton + deuteron_mass);
-                let gamow_energy = 2.0 * self.alpha * (reduced_mass * thermal_energy / 2.0).sqrt();
+                let gamow_energy = self.alpha * 2.0 * (reduced_mass * thermal_energy / 2.0).sqrt();
                 (-gamow_energy / thermal_energy).exp() * 1e-42
             }
-            _ => 0.0,
+            _ => T::from(0.0),
         }
     }
 
-    pub fn primordial_nucleosynthesis_success(&self) -> f64 {
+    pub fn primordial_nucleosynthesis_success(&self) -> T {
         let (m_proton, m_neutron, _) = self.get_validated_hadron_masses();
         let mass_diff = m_neutron - m_proton;
 
         let target_diff = 2.305e-30; // kg
         if mass_diff <= 0.0 {
-            return 0.0;
+            return T::from(0.0);
+        }
+
+        // Sin deuterón no arranca la cadena; con diprotón ligado el hidrógeno arde entero
+        let light_nuclei = self.light_nuclei();
+        let binding_viability = self.gate(light_nuclei.deuteron_margin) * self.gate(-light_nuclei.diproton_margin);
+        if binding_viability <= 0.0 {
+            return T::from(0.0);
         }
 
         let pp_cross_section = self.nuclear_cross_section("p_p_fusion");
         let dp_cross_section = self.nuclear_cross_section("d_p_fusion");
 
-        let pp_viability = if pp_cross_section > 1e-50 { 1.0 } else { 0.0 };
-        let dp_viability = if dp_cross_section > 1e-47 { 1.0 } else { 0.0 };
+        // Márgenes en décadas de sección eficaz
+        let pp_viability = self.gate((pp_cross_section / 1e-50).log10());
+        let dp_viability = self.gate((dp_cross_section / 1e-47).log10());
 
         let mass_diff_score = (-((mass_diff - target_diff) / target_diff).powi(2) / 0.01).exp();
 
-        pp_viability * dp_viability * mass_diff_score
+        let burning_efficiency = T::from(1.0) - (-self.laws.baryon_to_photon_ratio / ETA_BURNING_THRESHOLD).exp();
+        let neutron_supply = (self.thermal_history().primordial_helium_fraction / MIN_PRIMORDIAL_HELIUM).min(T::from(1.0));
+
+        binding_viability * pp_viability * dp_viability * mass_diff_score * burning_efficiency * neutron_supply
     }
 
 
-    pub fn calculate_jeans_mass(&self) -> f64 {
-        let temperature = 20.0; // K - nubes moleculares frías
+    pub fn calculate_jeans_mass(&self) -> T {
+        let galaxy = self.galaxy_formation();
+        let temperature = galaxy.cloud_temperature; // K - nubes frías dentro de la galaxia
+        let density = galaxy.cloud_density; // kg/m³
+        if temperature <= 0.0 || density <= 0.0 {
+            return T::from(0.0);
+        }
+
         let (m_proton, _, _) = self.get_validated_hadron_masses();
-        let sound_speed = (K_B * temperature / m_proton).sqrt();
-        let density: f64 = 1e-18; // kg/m³ - densidad típica de nubes moleculares
+        let sound_speed = (temperature * K_B / m_proton).sqrt();
 
-        (PI.powi(5) / 6.0).sqrt() * sound_speed.powi(3) / (self.laws.g.powf(1.5) * density.sqrt())
+        T::from((PI.powi(5) / 6.0).sqrt()) * sound_speed.powi(3) / (self.laws.g.powf(1.5) * density.sqrt())
     }
 
-    pub fn main_sequence_lifetime(&self, stellar_mass: f64) -> f64 {
+    pub fn main_sequence_lifetime(&self, stellar_mass: T) -> T {
         if stellar_mass <= 0.0 {
-            return 0.0;
+            return T::from(0.0);
         }
         
         let mass_ratio = stellar_mass / M_SOLAR;
         
         let solar_lifetime_years = 10e9; 
     
-    let lifetime = solar_lifetime_years * mass_ratio.powf(-2.5);
+    let lifetime = mass_ratio.powf(-2.5) * solar_lifetime_years;
     
     lifetime * 31557600.0
     }
 
-    pub fn chandrasekhar_mass(&self) -> f64 {
+    // La escala estelar sigue a la masa de Chandrasekhar; 1 M☉ en nuestro universo
+    pub fn typical_stellar_mass(&self) -> T {
+        let reference_chandrasekhar = (H_BAR * C / G_GRAVITATIONAL).powf(1.5) / reference_proton_mass().powi(2) / (4.0 * 2.0_f64.sqrt());
+        self.chandrasekhar_mass() * M_SOLAR / reference_chandrasekhar
+    }
+
+    // Homología con opacidad Thomson y núcleo regulado por Gamow, normalizada al Sol
+    pub fn stellar_surface_temperature(&self, stellar_mass: T) -> T {
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        if stellar_mass <= 0.0 || m_proton <= 0.0 {
+            return T::from(0.0);
+        }
+
+        let scaling = (self.laws.g / G_GRAVITATIONAL).powi(2)
+            * (stellar_mass / M_SOLAR)
+            * (m_proton / reference_proton_mass()).powi(5)
+            * (self.laws.mass_electron / ELECTRON_MASS_EXACT).powi(2)
+            * (self.alpha / ALPHA_EMPIRICAL).powi(2);
+        T::from(SOLAR_SURFACE_TEMPERATURE) * scaling.powf(0.25)
+    }
+
+    pub fn chandrasekhar_mass(&self) -> T {
         let (m_proton, _, _) = self.get_validated_hadron_masses();
         if m_proton <= 0.0 || self.laws.g <= 0.0 {
-            return 0.0;
+            return T::from(0.0);
         }
 
         let mu_e: f64 = 2.0; // Peso molecular promedio por electrón (para He/C/O)
-        let fundamental_scale = (H_BAR * C / self.laws.g).powf(1.5) / m_proton.powi(2);
+        let fundamental_scale = (T::from(H_BAR * C) / self.laws.g).powf(1.5) / m_proton.powi(2);
         
         fundamental_scale / (mu_e.powi(2) * (2.0_f64).sqrt())
     }
 
-    pub fn stellar_formation_epoch(&self) -> f64 {
+    pub fn stellar_formation_epoch(&self) -> T {
         let jeans_mass = self.calculate_jeans_mass();
         if jeans_mass <= 0.0 {
-            return 0.0;
+            return T::from(0.0);
+        }
+
+        // La nube fragmenta hasta la escala estelar; si su masa de Jeans no llega a quemar hidrógeno solo hay enanas marrones
+        let typical_mass = self.typical_stellar_mass();
+        let hydrogen_burning = self.gate((jeans_mass / (typical_mass * HYDROGEN_BURNING_FRACTION)).log10());
+        if hydrogen_burning <= 0.0 {
+            return T::from(0.0);
+        }
+        let stellar_mass = jeans_mass.min(typical_mass);
+
+        // Las primeras estrellas deben encenderse antes de que el universo recolapse
+        let recollapse_time = self.recollapse_time();
+        let ignition = if recollapse_time.is_infinite() {
+            T::from(1.0)
+        } else {
+            self.gate((recollapse_time / self.thermal_history().first_stars.time).log10())
+        };
+        if ignition <= 0.0 {
+            return T::from(0.0);
         }
         
-        let stellar_lifetime = self.main_sequence_lifetime(jeans_mass);
+        let stellar_lifetime = self.main_sequence_lifetime(stellar_mass);
 
         let minimum_lifetime = 1e6 * 365.25 * 24.0 * 3600.0; // 1 Myr
         let maximum_lifetime = 1e11 * 365.25 * 24.0 * 3600.0; // 100 Gyr
         
-        if stellar_lifetime < minimum_lifetime {
-            return 0.0;
-        }
+        let longevity = self.gate((stellar_lifetime / minimum_lifetime).log10());
         
         if stellar_lifetime > maximum_lifetime {
-            return (maximum_lifetime / stellar_lifetime).sqrt();
+            return hydrogen_burning * ignition * longevity * (T::from(maximum_lifetime) / stellar_lifetime).sqrt();
         }
 
-        1.0
+        hydrogen_burning * ignition * longevity
     }
-    pub fn heavy_element_creation(&self) -> f64 {
-    let core_temp = 1.5e8; // 150 MK
-    let thermal_energy_joules = K_B * core_temp;
+    pub fn heavy_element_creation(&self) -> T {
+    let core_temp = self.helium_burning_temperature(); // 150 MK en nuestro universo
+    let thermal_energy_joules = core_temp * K_B;
 
     let hoyle_resonance_offset_joules = 379e3 * 1.60218e-19; // 379 keV en Joules
 
     let reduced_mass = (4.0 * 8.0) / (4.0 + 8.0) * PROTON_MASS_EMPIRICAL; // Masa reducida de He-4 y Be-8
-    let coulomb_barrier_energy = (2.0 * 4.0 * self.laws.e.powi(2)) / (4.0 * PI * EPSILON_0 * 1e-14); // r ~ 10 fm
+    let contact_radius = self.nuclear_force_range() * HOYLE_CONTACT_RADIUS_RANGES; // r ~ 10 fm
+    if contact_radius <= 0.0 {
+        return T::from(0.0);
+    }
+    let coulomb_barrier_energy = (self.laws.e.powi(2) * (2.0 * 4.0)) / (contact_radius * (4.0 * PI * EPSILON_0));
     
     let gamow_factor = (coulomb_barrier_energy / thermal_energy_joules).sqrt();
-    let tunnel_probability = (-3.0 * gamow_factor / 2.0).exp();
+    let tunnel_probability = (-gamow_factor * 3.0 / 2.0).exp();
 
-    let resonance_probability = (hoyle_resonance_offset_joules / thermal_energy_joules) * (-hoyle_resonance_offset_joules / thermal_energy_joules).exp();
+    let resonance_probability = (T::from(hoyle_resonance_offset_joules) / thermal_energy_joules) * (T::from(-hoyle_resonance_offset_joules) / thermal_energy_joules).exp();
 
     let reaction_rate_score = (tunnel_probability * resonance_probability) * 1e18; // Factor de escala empírico
 
     let alpha_s_optimal = 0.118;
     let alpha_s_score = (-((self.laws.alpha_s - alpha_s_optimal).powi(2) / (alpha_s_optimal * 0.05).powi(2))).exp();
     
-    (reaction_rate_score * alpha_s_score).min(1.0)
+    (reaction_rate_score * alpha_s_score).min(T::from(1.0))
 }
 
-    pub fn black_hole_formation_potential(&self) -> f64 {
+    pub fn black_hole_formation_potential(&self) -> T {
         let chandrasekhar = self.chandrasekhar_mass();
         if chandrasekhar <= 0.0 {
-            return 0.0;
+            return T::from(0.0);
         }
         
         let tov_limit = chandrasekhar * 2.5;
@@ -341,63 +466,157 @@ impl AdvancedPhysicsEngine {
         let typical_massive_star = 25.0 * solar_mass;
 
         if tov_limit < typical_massive_star {
-            1.0 
+            T::from(1.0)
         } else {
-            (typical_massive_star / tov_limit).powf(1.5)
+            (T::from(typical_massive_star) / tov_limit).powf(1.5)
         }
     }
 }
 
-pub fn calculate_enhanced_fitness(laws: &CosmicLaw) -> (f64, u8) {
+pub fn calculate_enhanced_fitness<T: Real>(laws: &CosmicLaw<T>) -> (T, u8) {
+    let breakdown = calculate_fitness_breakdown(laws);
+    (breakdown.fitness, breakdown.complexity_level)
+}
+
+pub fn select_fitness_model(fitness_model: FitnessModel) {
+    let _ = FITNESS_MODEL.set(fitness_model);
+}
+
+pub fn selected_fitness_model() -> FitnessModel {
+    FITNESS_MODEL.get().copied().unwrap_or_default()
+}
+
+pub fn calculate_fitness_breakdown<T: Real>(laws: &CosmicLaw<T>) -> FitnessBreakdown<T> {
+    match selected_fitness_model() {
+        FitnessModel::Gated => gated_fitness_breakdown(laws),
+        FitnessModel::Smooth { sharpness } => smooth_fitness_breakdown(laws, sharpness),
+    }
+}
+
+fn gated_fitness_breakdown<T: Real>(laws: &CosmicLaw<T>) -> FitnessBreakdown<T> {
     let engine = AdvancedPhysicsEngine::new(laws.clone());
-    
-    let (mass_proton, mass_neutron, _mass_pion) = engine.get_validated_hadron_masses();
+    let mut breakdown = FitnessBreakdown::default();
 
-    if mass_proton >= mass_neutron || mass_proton + laws.mass_electron >= mass_neutron {
-        return (0.0, 0);
+    let dimensions = engine.extra_dimensions();
+    if !dimensions.atoms_stable {
+        breakdown.failure_reason = Some(format!("Átomos inestables en {} dimensiones (Ehrenfest)", dimensions.large_dimensions));
+        return breakdown;
+    }
+    if !dimensions.orbits_stable {
+        breakdown.failure_reason = Some("Órbitas gravitatorias inestables (Ehrenfest)".to_string());
+        return breakdown;
     }
 
-    let mut fitness = 0.0;
-    let mut complexity_level = 0;
+    // Protón, electrón e hidrógeno deben ser estables; n → p e⁻ ν̄ es solo un caso
+    if let Some(channel) = engine.essential_species_decay() {
+        breakdown.failure_reason = Some(format!("Especie esencial inestable: {}", channel));
+        return breakdown;
+    }
+
+    let light_nuclei = engine.light_nuclei();
+    if !light_nuclei.deuteron_bound() {
+        breakdown.failure_reason = Some("Deuterón no ligado: la nucleosíntesis no puede empezar".to_string());
+        return breakdown;
+    }
+    if light_nuclei.diproton_bound() {
+        breakdown.failure_reason = Some("Diprotón ligado: todo el hidrógeno arde en el Big Bang".to_string());
+        return breakdown;
+    }
 
-    let cosmic_viability = engine.cosmological_viability();
-    if cosmic_viability < 0.1 {
-        return (0.0, 0);
+    breakdown.cosmology = engine.cosmological_viability();
+    if breakdown.cosmology < 0.1 {
+        breakdown.failure_reason = Some("El universo recolapsa antes de formar estructuras".to_string());
+        return breakdown;
     }
-    fitness += 0.15 * cosmic_viability;
 
-    let nucleosynthesis_score = engine.primordial_nucleosynthesis_success();
-    fitness += 0.25 * nucleosynthesis_score;
+    // Los neutrinos masivos ya están dentro de la estructura y la BBN; se exponen aparte
+    breakdown.neutrino_suppression = engine.free_streaming_suppression();
+    breakdown.structure = engine.structure_formation_score();
+
+    // Cada etapa se evalúa con las constantes de su época
+    let history = engine.thermal_history();
+    let stellar_engine = engine.at_epoch(history.first_stars.time);
+    let stellar_death_time = history.first_stars.time + engine.main_sequence_lifetime(engine.typical_stellar_mass());
+    let remnant_engine = engine.at_epoch(stellar_death_time);
+    breakdown.neutrino_species_bbn = engine.relativistic_neutrino_species(history.weak_freeze_out.temperature);
+
+    breakdown.nucleosynthesis = engine.at_epoch(history.nucleosynthesis.time).primordial_nucleosynthesis_success();
+
+    breakdown.chemistry = engine.chemistry_score();
+
+    // La química solo es real si existen los núcleos que la sostienen
+    breakdown.periodic_table = engine.periodic_table_score();
 
-    if nucleosynthesis_score > 0.3 {
-        complexity_level = 1;
+    if breakdown.nucleosynthesis > 0.3 && breakdown.chemistry > 0.3 {
+        breakdown.complexity_level = 1;
 
-        let stellar_score = engine.stellar_formation_epoch();
-        fitness += 0.25 * stellar_score;
+        // Sin galaxias que se enfríen no hay nubes que fragmenten en estrellas
+        breakdown.stellar = if breakdown.structure > 0.3 {
+            stellar_engine.stellar_formation_epoch()
+        } else {
+            T::from(0.0)
+        };
+
+        if breakdown.stellar > 0.4 {
+            breakdown.complexity_level = 2;
+
+            breakdown.heavy_elements = remnant_engine.heavy_element_creation();
 
-        if stellar_score > 0.4 {
-            complexity_level = 2;
+            if breakdown.heavy_elements > 0.5 {
+                breakdown.complexity_level = 3;
 
-            let heavy_elements_score = engine.heavy_element_creation();
-            fitness += 0.20 * heavy_elements_score;
+                breakdown.black_holes = remnant_engine.black_hole_formation_potential();
 
-            if heavy_elements_score > 0.5 {
-                complexity_level = 3;
+                // Los planetas rocosos se forman con los metales de la primera generación
+                breakdown.planets = remnant_engine.habitable_planet_score();
 
-                let black_hole_score = engine.black_hole_formation_potential();
-                fitness += 0.15 * black_hole_score;
+                if breakdown.black_holes > 0.6 {
+                    breakdown.complexity_level = 4;
 
-                if black_hole_score > 0.6 {
-                    complexity_level = 4;
+                    if breakdown.black_holes > 0.8 && breakdown.heavy_elements > 0.7 {
+                        breakdown.complexity_level = 5;
 
-                    if black_hole_score > 0.8 && heavy_elements_score > 0.7 {
-                        complexity_level = 5;
-                        fitness += 0.05; // Bonus pequeño
+                        if breakdown.planets > 0.5 {
+                            breakdown.complexity_level = 6;
+                        }
                     }
                 }
             }
         }
     }
 
-    (fitness.min(1.0), complexity_level)
-}
\ No newline at end of file
+    let bonus = if breakdown.complexity_level >= 5 { COMPLEXITY_BONUS } else { 0.0 };
+    breakdown.uncapped_fitness = breakdown.weighted_components() + bonus;
+    breakdown.fitness = breakdown.uncapped_fitness.min(T::from(1.0));
+    breakdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_universe_forms_stars() {
+        let breakdown = gated_fitness_breakdown(&CosmicLaw::reference());
+        assert!(breakdown.failure_reason.is_none(), "{:?}", breakdown.failure_reason);
+        assert!(breakdown.stellar > 0.4, "etapa estelar: {}", breakdown.stellar);
+    }
+    #[test]
+    fn gmor_pion_sets_the_nuclear_range() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let pion_mev = engine.pion_mass() * C.powi(2) / MEV_TO_JOULE;
+        assert!((pion_mev - 139.57).abs() < 0.5, "m_π = {} MeV", pion_mev);
+        assert!((engine.nuclear_force_range() - 1.414e-15).abs() < 1e-17);
+
+        // m_π ∝ √(m_u + m_d): quarks 4× más ligeros duplican el alcance
+        let mut laws = CosmicLaw::reference();
+        laws.mass_up_quark /= 4.0;
+        laws.mass_down_quark /= 4.0;
+        let light = AdvancedPhysicsEngine::new(laws);
+        assert!((light.pion_mass() / engine.pion_mass() - 0.5).abs() < 1e-9);
+        // Con masas empíricas el alcance usa el pion medido y no sigue a los quarks
+        if !cfg!(feature = "empirical_validation") {
+            assert!(light.light_nuclei().diproton_bound());
+        }
+    }
+}
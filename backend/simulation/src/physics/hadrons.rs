@@ -0,0 +1,118 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::GEV_TO_KG;
+use crate::physics::engine::AdvancedPhysicsEngine;
+
+const DELTA_NUCLEON_SPLITTING_GEV: f64 = 0.293;
+const LIGHT_CONSTITUENT_REFERENCE_GEV: f64 = 0.328; // Masa constituyente u/d en el universo de referencia
+
+#[derive(Debug, Clone, Copy)]
+pub struct Baryon<T = f64> {
+    pub name: &'static str,
+    pub mass: T, // kg
+    pub charge: i8,
+}
+
+#[derive(Debug, Clone)]
+pub struct BaryonSpectrum<T = f64> {
+    pub baryons: Vec<Baryon<T>>,
+}
+
+impl<T: Real> BaryonSpectrum<T> {
+    pub fn lightest(&self) -> Baryon<T> {
+        *self
+            .baryons
+            .iter()
+            .min_by(|a, b| a.mass.value().total_cmp(&b.mass.value()))
+            .unwrap()
+    }
+
+    pub fn mass_of(&self, name: &str) -> T {
+        self.baryons.iter().find(|b| b.name == name).map(|b| b.mass).unwrap_or(T::from(0.0))
+    }
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    // De Rújula–Georgi–Glashow: M = Σ m_i + A Σ (s_i·s_j) / (m_i m_j), anclado al nucleón del motor
+    pub fn baryon_spectrum(&self) -> BaryonSpectrum<T> {
+        let laws = &self.laws;
+        let (m_proton, m_neutron, _) = self.get_validated_hadron_masses();
+
+        let u = self.constituent_quark_mass(laws.mass_up_quark, "up");
+        let d = self.constituent_quark_mass(laws.mass_down_quark, "down");
+        let s = self.constituent_quark_mass(laws.mass_strange_quark, "strange");
+        let c = self.constituent_quark_mass(laws.mass_charm_quark, "charm");
+        let b = self.constituent_quark_mass(laws.mass_bottom_quark, "bottom");
+        let light = (u + d) * 0.5;
+
+        // A ∝ α_s |ψ(0)|², calibrado con Δ − N = 293 MeV
+        let hyperfine_gev3 = self.strong_coupling_scaling()
+            * ((2.0 / 3.0) * DELTA_NUCLEON_SPLITTING_GEV * LIGHT_CONSTITUENT_REFERENCE_GEV.powi(2));
+        let hyperfine_kg = |pair_sum: T| pair_sum * hyperfine_gev3 * GEV_TO_KG.powi(3);
+
+        // Autoenergía electromagnética ∝ Q², extraída de la separación n − p del motor
+        let electromagnetic = (m_proton - m_neutron) - (u - d);
+        let nucleon_average = (m_proton + m_neutron) * 0.5;
+        let nucleon_quarks = ((u * 2.0 + d) + (u + d * 2.0)) * 0.5;
+        let nucleon_hyperfine = hyperfine_kg(T::from(-0.75) / (light * light));
+
+        let baryon = |name: &'static str, quarks: T, pair_sum: T, charge: i8| Baryon {
+            name,
+            mass: nucleon_average + (quarks - nucleon_quarks) + (hyperfine_kg(pair_sum) - nucleon_hyperfine)
+                + electromagnetic * ((charge as f64).powi(2) - 0.5),
+            charge,
+        };
+
+        // Espín 1/2 con un par ligero en espín 0: Σ s_i·s_j / m_i m_j = −3/(4 m²)
+        let singlet = |m: T| T::from(-0.75) / (m * m);
+        // Espín 1/2 con un par en espín 1 (m_a m_a) y tercer quark m_b
+        let triplet_half = |m_a: T, m_b: T| T::from(0.25) / (m_a * m_a) - T::from(1.0) / (m_a * m_b);
+        // Espín 3/2: todos los pares con s_i·s_j = 1/4
+        let quartet = |m1: T, m2: T, m3: T| (T::from(1.0) / (m1 * m2) + T::from(1.0) / (m1 * m3) + T::from(1.0) / (m2 * m3)) * 0.25;
+
+        BaryonSpectrum {
+            baryons: vec![
+                baryon("p", u * 2.0 + d, singlet(light), 1),
+                baryon("n", u + d * 2.0, singlet(light), 0),
+                baryon("Δ⁺⁺", u * 3.0, quartet(u, u, u), 2),
+                baryon("Δ⁺", u * 2.0 + d, quartet(u, u, d), 1),
+                baryon("Δ⁰", u + d * 2.0, quartet(u, d, d), 0),
+                baryon("Δ⁻", d * 3.0, quartet(d, d, d), -1),
+                baryon("Λ", u + d + s, singlet(light), 0),
+                baryon("Σ⁺", u * 2.0 + s, triplet_half(light, s), 1),
+                baryon("Σ⁰", u + d + s, triplet_half(light, s), 0),
+                baryon("Σ⁻", d * 2.0 + s, triplet_half(light, s), -1),
+                baryon("Ξ⁰", u + s * 2.0, triplet_half(s, light), 0),
+                baryon("Ξ⁻", d + s * 2.0, triplet_half(s, light), -1),
+                baryon("Ω⁻", s * 3.0, quartet(s, s, s), -1),
+                baryon("Λc⁺", u + d + c, singlet(light), 1),
+                baryon("Λb⁰", u + d + b, singlet(light), 0),
+            ],
+        }
+    }
+
+    // El barión más ligero es estable por conservación del número bariónico
+    pub fn baryon_category(&self) -> &'static str {
+        match self.baryon_spectrum().lightest().name {
+            "p" => "Ordinario",
+            "n" => "Neutrónico",
+            "Δ⁺⁺" | "Δ⁺" | "Δ⁰" | "Δ⁻" => "Exótico: Δ estable",
+            "Λ" | "Σ⁺" | "Σ⁰" | "Σ⁻" | "Ξ⁰" | "Ξ⁻" | "Ω⁻" => "Exótico: hiperón estable",
+            _ => "Exótico: barión pesado estable",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    #[test]
+    fn reference_proton_is_lightest_baryon() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let spectrum = engine.baryon_spectrum();
+        assert_eq!(spectrum.lightest().name, "p");
+        assert!(spectrum.mass_of("Δ⁺⁺") > spectrum.mass_of("n"));
+        assert_eq!(spectrum.mass_of("no-existe"), 0.0);
+    }
+}
@@ -1,2 +1,14 @@
 pub mod engine;
 pub mod constants;
+pub mod structure;
+pub mod dark_matter;
+pub mod thermal_history;
+pub mod chemistry;
+pub mod nuclear;
+pub mod hadrons;
+pub mod decays;
+pub mod dimensions;
+pub mod varying_constants;
+pub mod planets;
+pub mod neutrinos;
+pub mod smooth_fitness;
@@ -0,0 +1,99 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::engine::AdvancedPhysicsEngine;
+use std::f64::consts::PI;
+
+const NEUTRINO_DOF_PER_SPECIES: f64 = 1.75; // (7/8) × 2 helicidades
+const NEUTRINO_NUMBER_PER_PHOTON: f64 = 3.0 / 11.0; // ν + ν̄ por especie tras la aniquilación e±
+const NEUTRINO_MASS_EIGENSTATES: f64 = 3.0;
+const FREE_STREAMING_SUPPRESSION: f64 = 4.0; // ΔP/P ≈ −8 f_ν → δ ∝ e^(−4 f_ν)
+
+#[derive(Debug, Clone, Copy)]
+pub struct NeutrinoSector<T = f64> {
+    pub total_mass: T,                 // kg - Σ m_ν
+    pub effective_species_bbn: T,      // N_eff en el congelamiento débil
+    pub density_parameter: T,          // Ω_ν
+    pub matter_fraction: T,            // f_ν = ρ_ν / ρ_m
+    pub free_streaming_suppression: T, // δ_con ν / δ_sin ν en escalas galácticas
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    // Especies ligeras que siguen siendo radiación a T; cada autoestado pesa un tercio de N_ν
+    pub fn relativistic_neutrino_species(&self, temperature: T) -> T {
+        let thermal_energy = temperature * K_B;
+        let relativistic_states = self
+            .laws
+            .neutrino_masses
+            .iter()
+            .filter(|&&mass| mass * C.powi(2) < thermal_energy)
+            .count() as f64;
+        self.laws.neutrino_species * relativistic_states / NEUTRINO_MASS_EIGENSTATES
+    }
+
+    pub fn neutrino_degrees_of_freedom(&self, temperature: T) -> T {
+        self.relativistic_neutrino_species(temperature) * NEUTRINO_DOF_PER_SPECIES
+    }
+
+    pub fn neutrino_matter_per_photon(&self) -> T {
+        let total_mass: T = self.laws.neutrino_masses.iter().copied().sum();
+        self.laws.neutrino_species * NEUTRINO_NUMBER_PER_PHOTON * total_mass / NEUTRINO_MASS_EIGENSTATES
+    }
+
+    pub fn neutrino_density_parameter(&self) -> T {
+        let photon_density = 2.0 * ZETA_3 / PI.powi(2) * (K_B * CMB_TEMPERATURE_TODAY / (H_BAR * C)).powi(3);
+        self.neutrino_matter_per_photon() * photon_density / self.critical_density()
+    }
+
+    pub fn neutrino_matter_fraction(&self) -> T {
+        let matter_density = self.matter_density_parameter();
+        if matter_density <= 0.0 {
+            return T::from(0.0);
+        }
+        self.neutrino_density_parameter() / matter_density
+    }
+
+    // Los neutrinos masivos escapan de los pozos galácticos y frenan el crecimiento de la materia
+    pub fn free_streaming_suppression(&self) -> T {
+        (self.neutrino_matter_fraction() * -FREE_STREAMING_SUPPRESSION).exp()
+    }
+
+    pub fn neutrino_sector(&self) -> NeutrinoSector<T> {
+        NeutrinoSector {
+            total_mass: self.laws.neutrino_masses.iter().copied().sum(),
+            effective_species_bbn: self.relativistic_neutrino_species(self.weak_freeze_out_temperature()),
+            density_parameter: self.neutrino_density_parameter(),
+            matter_fraction: self.neutrino_matter_fraction(),
+            free_streaming_suppression: self.free_streaming_suppression(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    #[test]
+    fn reference_neutrinos_are_three_light_species() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let sector = engine.neutrino_sector();
+        assert!((sector.effective_species_bbn - 3.0).abs() < 1e-12);
+        // Ω_ν h² = Σm_ν / 93.14 eV
+        let expected = sector.total_mass / EV_TO_KG / 93.14 / HUBBLE_PARAMETER_H.powi(2);
+        assert!((sector.density_parameter / expected - 1.0).abs() < 0.02, "Ω_ν = {} frente a {}", sector.density_parameter, expected);
+        assert!(sector.free_streaming_suppression > 0.95);
+    }
+
+    #[test]
+    fn heavy_neutrinos_drop_out_of_n_eff_and_damp_structure() {
+        let mut laws = CosmicLaw::reference();
+        laws.neutrino_masses = [0.0, 0.0, 2.0 * MEV_TO_KG];
+        assert!((AdvancedPhysicsEngine::new(laws).neutrino_sector().effective_species_bbn - 2.0).abs() < 1e-12);
+
+        let mut laws = CosmicLaw::reference();
+        laws.neutrino_masses = [EV_TO_KG; 3];
+        let sector = AdvancedPhysicsEngine::new(laws).neutrino_sector();
+        assert!(sector.matter_fraction > 0.1);
+        assert!(sector.free_streaming_suppression < 0.7);
+    }
+}
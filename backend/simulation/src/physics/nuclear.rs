@@ -0,0 +1,404 @@
+use crate::core::models::CosmicLaw;
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::engine::AdvancedPhysicsEngine;
+use std::collections::BTreeSet;
+use std::f64::consts::PI;
+use std::sync::OnceLock;
+
+// Coeficientes de Weizsäcker en MeV para nuestro universo
+const VOLUME_COEFFICIENT: f64 = 15.75;
+const SURFACE_COEFFICIENT: f64 = 17.8;
+const COULOMB_COEFFICIENT: f64 = 0.711;
+const ASYMMETRY_COEFFICIENT: f64 = 23.7;
+const PAIRING_COEFFICIENT: f64 = 11.18;
+
+const ALPHA_S_HADRONIC_REFERENCE: f64 = 0.4993; // running_alpha_s(1 GeV) con α_s(M_Z) = 0.1181
+const ALPHA_PARTICLE_BINDING_MEV: f64 = 28.3;
+const NUCLEAR_ATTEMPT_TIME: f64 = 1e-21; // s - frecuencia de asalto a la barrera
+const MIN_STABLE_LIFETIME: f64 = 1e9 * 31557600.0; // 1 Gyr
+const MAX_MASS_NUMBER: u32 = 400;
+pub const REFERENCE_STABLE_ELEMENTS: f64 = 80.0; // Con al menos un isótopo estable: Z ≤ 82 sin Tc ni Pm
+
+// Pozos cuadrados de alcance ħ/m_π c, calibrados en el universo de referencia
+const DEUTERON_BINDING_MEV: f64 = 2.224; // ³S₁
+const SINGLET_THRESHOLD_FRACTION: f64 = 0.92; // ¹S₀: 8% por debajo del umbral de ligadura
+
+#[derive(Debug, Clone, Copy)]
+pub struct NuclearCoefficients<T = f64> {
+    pub volume: T,
+    pub surface: T,
+    pub coulomb: T,
+    pub asymmetry: T,
+    pub pairing: T,
+}
+
+#[derive(Debug, Clone)]
+pub struct PeriodicTable {
+    pub stable_nuclei: usize,
+    pub stable_elements: usize,
+    pub heaviest_element: u32,
+    pub carbon_stable: bool,
+    pub nitrogen_stable: bool,
+    pub oxygen_stable: bool,
+    pub iron_stable: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LightNuclei<T = f64> {
+    pub nuclear_range: T,     // m - alcance de Yukawa del pion
+    pub deuteron_binding: T,  // MeV
+    pub diproton_binding: T,  // MeV - incluye la repulsión de Coulomb
+    pub deuteron_margin: T,   // Décimas de fuerza del pozo sobre el umbral de ligadura
+    pub diproton_margin: T,
+}
+
+impl PeriodicTable {
+    fn from_elements(stable_nuclei: usize, elements: &BTreeSet<u32>) -> Self {
+        PeriodicTable {
+            stable_nuclei,
+            stable_elements: elements.len(),
+            heaviest_element: elements.iter().next_back().copied().unwrap_or(0),
+            carbon_stable: elements.contains(&6),
+            nitrogen_stable: elements.contains(&7),
+            oxygen_stable: elements.contains(&8),
+            iron_stable: elements.contains(&26),
+        }
+    }
+}
+
+impl<T: Real> LightNuclei<T> {
+    pub fn deuteron_bound(&self) -> bool {
+        self.deuteron_binding > 0.0
+    }
+
+    pub fn diproton_bound(&self) -> bool {
+        self.diproton_binding > 0.0
+    }
+}
+
+impl<T: Real> NuclearCoefficients<T> {
+    // Fórmula semiempírica de masas: energía de ligadura en MeV
+    pub fn binding_energy(&self, mass_number: u32, atomic_number: u32) -> T {
+        if atomic_number > mass_number || mass_number == 0 {
+            return T::from(0.0);
+        }
+        let a = mass_number as f64;
+        let z = atomic_number as f64;
+        let n = a - z;
+
+        let pairing = if !mass_number.is_multiple_of(2) {
+            T::from(0.0)
+        } else if atomic_number.is_multiple_of(2) {
+            self.pairing / a.sqrt()
+        } else {
+            -self.pairing / a.sqrt()
+        };
+
+        self.volume * a - self.surface * a.powf(2.0 / 3.0) - self.coulomb * z * (z - 1.0) / a.cbrt()
+            - self.asymmetry * (n - z).powi(2) / a
+            + pairing
+    }
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    // Intensidad de la interacción fuerte a escala hadrónica relativa a nuestro universo
+    pub fn strong_coupling_scaling(&self) -> T {
+        self.running_alpha_s(1.0) / ALPHA_S_HADRONIC_REFERENCE
+    }
+
+    // Términos fuertes escalan con α_s hadrónica; el de Coulomb con α / r₀, y r₀ con el alcance del pion
+    pub fn nuclear_coefficients(&self) -> NuclearCoefficients<T> {
+        let strong_scaling = self.strong_coupling_scaling();
+        let reference_range = H_BAR / (PION_MASS_CHARGED * C);
+        let radius_scaling = T::from(reference_range) / self.nuclear_force_range();
+        NuclearCoefficients {
+            volume: strong_scaling * VOLUME_COEFFICIENT,
+            surface: strong_scaling * SURFACE_COEFFICIENT,
+            coulomb: self.alpha * COULOMB_COEFFICIENT / ALPHA_EMPIRICAL * radius_scaling,
+            asymmetry: strong_scaling * ASYMMETRY_COEFFICIENT,
+            pairing: strong_scaling * PAIRING_COEFFICIENT,
+        }
+    }
+
+    pub fn light_nuclei(&self) -> LightNuclei<T> {
+        let (m_proton, m_neutron, _) = self.get_validated_hadron_masses();
+        let nuclear_range = self.nuclear_force_range();
+        if nuclear_range <= 0.0 || m_proton <= 0.0 {
+            return LightNuclei {
+                nuclear_range: T::from(0.0),
+                deuteron_binding: T::from(0.0),
+                diproton_binding: T::from(0.0),
+                deuteron_margin: T::from(-10.0),
+                diproton_margin: T::from(-10.0),
+            };
+        }
+
+        let strong_scaling = self.strong_coupling_scaling();
+        let to_mev = C.powi(2) / MEV_TO_JOULE;
+
+        let deuteron_reduced_mass = m_proton * m_neutron / (m_proton + m_neutron) * to_mev;
+        let (triplet_depth, singlet_depth) = reference_well_depths();
+        let deuteron_depth = strong_scaling * triplet_depth;
+        let deuteron_binding = square_well_binding(deuteron_depth, deuteron_reduced_mass, nuclear_range);
+
+        // Dos protones solo comparten el canal singlete y además se repelen
+        let coulomb_barrier = self.alpha * H_BAR * C / nuclear_range / MEV_TO_JOULE;
+        let diproton_depth = strong_scaling * singlet_depth - coulomb_barrier;
+        let diproton_binding = square_well_binding(diproton_depth, m_proton * 0.5 * to_mev, nuclear_range);
+
+        LightNuclei {
+            nuclear_range,
+            deuteron_binding,
+            diproton_binding,
+            deuteron_margin: square_well_margin(deuteron_depth, deuteron_reduced_mass, nuclear_range),
+            diproton_margin: square_well_margin(diproton_depth, m_proton * 0.5 * to_mev, nuclear_range),
+        }
+    }
+
+    // Geiger–Nuttall: t = t₀ exp(2π Z_d α √(μc² / 2Q))
+    fn alpha_decay_lifetime(&self, coefficients: &NuclearCoefficients<T>, mass_number: u32, atomic_number: u32) -> T {
+        if mass_number < 8 || atomic_number < 4 {
+            return T::from(f64::INFINITY);
+        }
+        let alpha_binding = coefficients.volume * ALPHA_PARTICLE_BINDING_MEV / VOLUME_COEFFICIENT;
+        let q_value = coefficients.binding_energy(mass_number - 4, atomic_number - 2) + alpha_binding
+            - coefficients.binding_energy(mass_number, atomic_number);
+        if q_value <= 0.0 {
+            return T::from(f64::INFINITY);
+        }
+
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        let daughter_mass = (mass_number - 4) as f64;
+        let reduced_mass_mev = m_proton * 4.0 * C.powi(2) / MEV_TO_JOULE * daughter_mass / (daughter_mass + 4.0);
+        let daughter_charge = (atomic_number - 2) as f64;
+        let gamow_exponent = self.alpha * (2.0 * PI * daughter_charge) * (reduced_mass_mev / (q_value * 2.0)).sqrt();
+
+        gamow_exponent.min(T::from(700.0)).exp() * NUCLEAR_ATTEMPT_TIME
+    }
+
+    fn is_stable_nucleus(&self, coefficients: &NuclearCoefficients<T>, mass_number: u32, atomic_number: u32) -> bool {
+        let binding = coefficients.binding_energy(mass_number, atomic_number);
+        if binding <= 0.0 || self.alpha * (atomic_number as f64) >= 1.0 {
+            return false;
+        }
+
+        // Líneas de goteo: separar un neutrón o un protón debe costar energía
+        let neutron_separation = binding - coefficients.binding_energy(mass_number - 1, atomic_number);
+        let proton_separation = binding - coefficients.binding_energy(mass_number - 1, atomic_number - 1);
+        if neutron_separation <= 0.0 || proton_separation <= 0.0 {
+            return false;
+        }
+
+        // Fisión espontánea cuando la repulsión de Coulomb supera a la tensión superficial
+        let a = mass_number as f64;
+        let z = atomic_number as f64;
+        let fissility = coefficients.coulomb * z.powi(2) / a.cbrt() / (coefficients.surface * 2.0 * a.powf(2.0 / 3.0));
+        if fissility >= 1.0 {
+            return false;
+        }
+
+        self.alpha_decay_lifetime(coefficients, mass_number, atomic_number) > MIN_STABLE_LIFETIME
+    }
+
+    // Para cada isóbaro A se queda el Z de menor masa atómica (valle de estabilidad β)
+    pub fn periodic_table(&self) -> PeriodicTable {
+        let coefficients = self.nuclear_coefficients();
+        let (m_proton, m_neutron, _) = self.get_validated_hadron_masses();
+        let beta_gap_mev = (m_neutron - m_proton - self.laws.mass_electron) * C.powi(2) / MEV_TO_JOULE;
+
+        let mut stable_nuclei = 0;
+        let mut elements = BTreeSet::new();
+
+        // Sin fuerza nuclear de alcance finito o sin carga no hay núcleos que ordenar en una tabla
+        if self.nuclear_force_range() <= 0.0 || self.alpha <= 0.0 || !beta_gap_mev.is_finite() {
+            return PeriodicTable::from_elements(stable_nuclei, &elements);
+        }
+
+        // El protón libre es hidrógeno si no puede capturar su electrón
+        if beta_gap_mev > 0.0 {
+            stable_nuclei += 1;
+            elements.insert(1);
+        }
+
+        for mass_number in 2..=MAX_MASS_NUMBER {
+            let a = mass_number as f64;
+            let coulomb = coefficients.coulomb / a.cbrt();
+            let optimal_z = (coefficients.asymmetry * 4.0 + coulomb + beta_gap_mev)
+                / (coulomb * 2.0 + coefficients.asymmetry * 8.0 / a);
+
+            let low = (optimal_z.value().floor() as i64 - 1).max(1) as u32;
+            let high = ((optimal_z.value().ceil() as i64 + 1).max(1) as u32).min(mass_number);
+
+            let atomic_mass = |z: u32| -beta_gap_mev * z as f64 - coefficients.binding_energy(mass_number, z);
+            let best_z = (low..=high).min_by(|&x, &y| atomic_mass(x).value().total_cmp(&atomic_mass(y).value()));
+
+            if let Some(z) = best_z {
+                if self.is_stable_nucleus(&coefficients, mass_number, z) {
+                    stable_nuclei += 1;
+                    elements.insert(z);
+                }
+            }
+        }
+
+        PeriodicTable::from_elements(stable_nuclei, &elements)
+    }
+
+    // Las mismas puertas que la nucleosíntesis: sin deuterón, o con diprotón, no se llega a construir la tabla.
+    // Es un recuento de elementos: sale por T::from(f64) sin derivada; solo la llevan las puertas que lo multiplican
+    pub fn periodic_table_score(&self) -> T {
+        let light_nuclei = self.light_nuclei();
+        if !light_nuclei.deuteron_bound() || light_nuclei.diproton_bound() {
+            return T::from(0.0);
+        }
+
+        let table = self.periodic_table();
+        let richness = (table.stable_elements as f64 / REFERENCE_STABLE_ELEMENTS).min(1.0);
+        let life_elements = [table.carbon_stable, table.nitrogen_stable, table.oxygen_stable, table.iron_stable]
+            .iter()
+            .filter(|&&stable| stable)
+            .count() as f64
+            / 4.0;
+
+        T::from(0.5 * richness + 0.5 * life_elements)
+    }
+}
+
+// Las profundidades dependen de la masa nucleónica que use el motor (teórica o empírica)
+fn reference_well_depths() -> (f64, f64) {
+    static WELL_DEPTHS: OnceLock<(f64, f64)> = OnceLock::new();
+    *WELL_DEPTHS.get_or_init(|| {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let (m_proton, m_neutron, _) = engine.get_validated_hadron_masses();
+        let range = engine.nuclear_force_range();
+        let to_mev = C.powi(2) / MEV_TO_JOULE;
+
+        let deuteron_reduced_mass = m_proton * m_neutron / (m_proton + m_neutron) * to_mev;
+        let triplet = square_well_depth_for_binding(DEUTERON_BINDING_MEV, deuteron_reduced_mass, range);
+        let singlet = SINGLET_THRESHOLD_FRACTION * square_well_depth_for_strength(PI / 2.0, m_proton * 0.5 * to_mev, range);
+        (triplet, singlet)
+    })
+}
+
+// Inversa de square_well_strength
+fn square_well_depth_for_strength(strength: f64, reduced_mass: f64, range: f64) -> f64 {
+    let hbar_c = H_BAR * C / MEV_TO_JOULE; // MeV·m
+    (strength * hbar_c / range).powi(2) / (reduced_mass * 2.0)
+}
+
+// Profundidad que liga el estado s con la energía dada: kR cot(kR) = −κR con kR ∈ (π/2, π)
+fn square_well_depth_for_binding(binding: f64, reduced_mass: f64, range: f64) -> f64 {
+    let kappa_range = (binding / square_well_depth_for_strength(1.0, reduced_mass, range)).sqrt();
+    let (mut low, mut high) = (PI / 2.0, PI);
+    for _ in 0..60 {
+        let x = 0.5 * (low + high);
+        if x / x.tan() + kappa_range > 0.0 {
+            low = x;
+        } else {
+            high = x;
+        }
+    }
+    binding + square_well_depth_for_strength(0.5 * (low + high), reduced_mass, range)
+}
+
+// √(2μV) R / ħc: el estado s aparece al superar π/2
+fn square_well_strength<T: Real>(depth: T, reduced_mass: T, range: T) -> T {
+    let hbar_c = H_BAR * C / MEV_TO_JOULE; // MeV·m
+    (reduced_mass * 2.0 * depth.max(T::from(0.0))).sqrt() * range / hbar_c
+}
+
+// A diferencia de la energía de ligadura, sigue variando cuando no hay estado ligado
+fn square_well_margin<T: Real>(depth: T, reduced_mass: T, range: T) -> T {
+    (square_well_strength(depth, reduced_mass, range) / (PI / 2.0) - 1.0) * 10.0
+}
+
+// Pozo cuadrado en MeV: energía de ligadura del estado s fundamental (0 si no existe)
+fn square_well_binding<T: Real>(depth: T, reduced_mass: T, range: T) -> T {
+    let hbar_c = H_BAR * C / MEV_TO_JOULE; // MeV·m
+    let well_strength = square_well_strength(depth, reduced_mass, range);
+    if well_strength <= PI / 2.0 {
+        return T::from(0.0);
+    }
+
+    // k cot(kR) = −κ con kR ∈ (π/2, π)
+    let strength = well_strength.value();
+    let mut low = PI / 2.0;
+    let mut high = strength.min(PI);
+    for _ in 0..60 {
+        let x = 0.5 * (low + high);
+        let kappa_range = (strength.powi(2) - x.powi(2)).max(0.0).sqrt();
+        if x / x.tan() + kappa_range > 0.0 {
+            low = x;
+        } else {
+            high = x;
+        }
+    }
+
+    // La bisección solo fija el valor; un paso de Newton con residuo nulo aporta dx/dW por la función implícita
+    let root = 0.5 * (low + high);
+    let kappa_range = (strength.powi(2) - root.powi(2)).max(0.0).sqrt();
+    let slope = 1.0 / root.tan() - root * (1.0 + 1.0 / root.tan().powi(2)) - root / kappa_range;
+    let residual = T::from(root) / T::from(root).tan() + (well_strength.powi(2) - root.powi(2)).max(T::from(0.0)).sqrt();
+    let correction = (residual - T::from(residual.value())) / slope;
+    let x = if correction.is_finite() { T::from(root) - correction } else { T::from(root) };
+    depth - (x * hbar_c / range).powi(2) / (reduced_mass * 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    #[test]
+    fn reference_deuteron_is_calibrated() {
+        let light_nuclei = AdvancedPhysicsEngine::new(CosmicLaw::reference()).light_nuclei();
+        assert!((light_nuclei.deuteron_binding - 2.224).abs() < 0.05, "{}", light_nuclei.deuteron_binding);
+        assert!(!light_nuclei.diproton_bound());
+    }
+
+    #[test]
+    fn coulomb_repulsion_keeps_the_diproton_shallower() {
+        let charged = AdvancedPhysicsEngine::new(CosmicLaw::reference()).light_nuclei();
+        let mut laws = CosmicLaw::reference();
+        laws.e = 0.0;
+        let neutral = AdvancedPhysicsEngine::new(laws).light_nuclei();
+        assert!(neutral.diproton_margin > charged.diproton_margin);
+    }
+
+    #[test]
+    fn well_depth_inverts_the_binding_energy() {
+        let (reduced_mass, range) = (469.5, 1.41e-15);
+        let depth = square_well_depth_for_binding(DEUTERON_BINDING_MEV, reduced_mass, range);
+        assert!((square_well_binding(depth, reduced_mass, range) - DEUTERON_BINDING_MEV).abs() < 1e-6);
+        let threshold = square_well_depth_for_strength(PI / 2.0, reduced_mass, range);
+        assert!((square_well_strength(threshold, reduced_mass, range) - PI / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn square_well_binds_only_past_threshold() {
+        let (reduced_mass, range) = (469.5, 2.0e-15);
+        assert_eq!(square_well_binding(5.0, reduced_mass, range), 0.0);
+        assert_eq!(square_well_binding(-5.0, reduced_mass, range), 0.0);
+        let shallow = square_well_binding(40.0, reduced_mass, range);
+        let deep = square_well_binding(60.0, reduced_mass, range);
+        assert!(shallow > 0.0 && deep > shallow);
+        assert!(square_well_margin(5.0, reduced_mass, range) < 0.0);
+    }
+
+    #[test]
+    fn reference_table_lands_near_the_observed_count() {
+        let table = AdvancedPhysicsEngine::new(CosmicLaw::reference()).periodic_table();
+        let error = (table.stable_elements as f64 / REFERENCE_STABLE_ELEMENTS - 1.0).abs();
+        assert!(error <= 0.15, "{} elementos", table.stable_elements);
+        assert!(table.carbon_stable && table.iron_stable);
+    }
+
+    #[test]
+    fn periodic_table_needs_electric_charge() {
+        let mut laws = CosmicLaw::reference();
+        laws.e = 0.0;
+        let engine = AdvancedPhysicsEngine::new(laws);
+        assert_eq!(engine.periodic_table().stable_elements, 0);
+        assert_eq!(engine.periodic_table_score(), 0.0);
+    }
+}
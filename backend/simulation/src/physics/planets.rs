@@ -0,0 +1,201 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::engine::{reference_proton_mass, AdvancedPhysicsEngine};
+use std::f64::consts::PI;
+
+const EARTH_MASS: f64 = 5.972e24; // kg
+const EARTH_RADIUS: f64 = 6.371e6; // m
+const BOHR_RADIUS_REFERENCE: f64 = 5.29177210903e-11; // m
+
+// El agua líquida vive entre la fusión y la ebullición, ambas fijadas por los puentes de hidrógeno ∝ Ry
+const WATER_FREEZING_REFERENCE: f64 = 273.15; // K
+const WATER_BOILING_REFERENCE: f64 = 373.15; // K
+const WATER_MOLECULE_NUCLEONS: f64 = 18.0;
+
+const PLANET_ALBEDO: f64 = 0.3;
+const GREENHOUSE_WARMING: f64 = 1.13; // T_superficie / T_equilibrio en la Tierra (288 K / 255 K)
+const ATMOSPHERE_RETENTION_THRESHOLD: f64 = 54.0; // v_esc > 6 v_rms durante miles de millones de años
+const MIN_BOND_TO_THERMAL_RATIO: f64 = 30.0; // D₀(H₂) / kT: por debajo las moléculas orgánicas se disocian
+const MIN_ORBIT_STELLAR_RADII: f64 = 2.0; // Más cerca la estrella engulle o desgarra el planeta
+
+// Marea: t = ω a⁶ I Q / (3 G M★² k₂ R⁵) con I = 0.33 M R²
+const INITIAL_SPIN_RATE: f64 = 1.454e-4; // rad/s - día primordial de 12 h
+const TIDAL_QUALITY_FACTOR: f64 = 100.0;
+const TIDAL_LOVE_NUMBER: f64 = 0.3;
+const MOMENT_OF_INERTIA_FACTOR: f64 = 0.33;
+const TIDAL_LOCK_PENALTY: f64 = 0.5; // Un lado en noche perpetua: solo atmósferas gruesas redistribuyen el calor
+
+const COMPLEX_LIFE_TIME: f64 = 4.0e9 * 31557600.0; // s - lo que tardó la Tierra en producir vida compleja
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlanetarySystem<T = f64> {
+    pub planet_mass: T,           // kg - planeta rocoso típico
+    pub planet_radius: T,         // m
+    pub stellar_luminosity: T,    // W
+    pub stellar_radius: T,        // m
+    pub habitable_zone_inner: T,  // m - el agua hierve
+    pub habitable_zone_outer: T,  // m - el agua se congela
+    pub surface_temperature: T,   // K - planeta en el centro de la zona habitable
+    pub bond_to_thermal_ratio: T, // D₀(H₂) / kT_superficie
+    pub atmosphere_retention: T,  // G M m_H₂O / (R k T)
+    pub tidal_locking_time: T,    // s
+    pub stellar_lifetime: T,      // s
+}
+
+impl<T: Real> PlanetarySystem<T> {
+    pub fn tidally_locked(&self) -> bool {
+        self.tidal_locking_time < self.stellar_lifetime
+    }
+
+    pub fn habitable_zone_exists(&self) -> bool {
+        self.habitable_zone_inner > self.stellar_radius * MIN_ORBIT_STELLAR_RADII
+    }
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    // Press–Lightman: un cuerpo rocoso deja de serlo cuando la gravedad aplasta los átomos, M ~ (α/α_G)^(3/2) m_p
+    pub fn typical_planet_mass(&self) -> T {
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        if m_proton <= 0.0 || self.laws.g <= 0.0 {
+            return T::from(0.0);
+        }
+
+        let atomic_gravity_scale = |alpha: T, g: T, m_p: T| (alpha * H_BAR * C / g).powf(1.5) / m_p.powi(2);
+        let reference_scale = atomic_gravity_scale(T::from(ALPHA_EMPIRICAL), T::from(G_GRAVITATIONAL), T::from(reference_proton_mass()));
+        T::from(EARTH_MASS) * atomic_gravity_scale(self.alpha, self.laws.g, m_proton) / reference_scale
+    }
+
+    // Densidad atómica ρ ∝ m_p / a₀³
+    pub fn planet_radius(&self, planet_mass: T) -> T {
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        if planet_mass <= 0.0 || m_proton <= 0.0 {
+            return T::from(0.0);
+        }
+
+        T::from(EARTH_RADIUS)
+            * (planet_mass / EARTH_MASS).cbrt()
+            * (self.bohr_radius() / BOHR_RADIUS_REFERENCE)
+            * (T::from(reference_proton_mass()) / m_proton).cbrt()
+    }
+
+    // L = M / t: la misma fracción de la masa se quema a lo largo de la secuencia principal
+    pub fn stellar_luminosity(&self, stellar_mass: T) -> T {
+        let lifetime = self.main_sequence_lifetime(stellar_mass);
+        if lifetime <= 0.0 {
+            return T::from(0.0);
+        }
+
+        T::from(SOLAR_LUMINOSITY) * (stellar_mass / M_SOLAR) * (self.main_sequence_lifetime(T::from(M_SOLAR)) / lifetime)
+    }
+
+    // Ventana de agua líquida escalada con la energía de enlace molecular
+    pub fn liquid_water_window(&self) -> (T, T) {
+        let reference_rydberg = 0.5 * ALPHA_EMPIRICAL.powi(2) * ELECTRON_MASS_EXACT * C.powi(2);
+        let scaling = self.rydberg_energy() / reference_rydberg;
+        (scaling * WATER_FREEZING_REFERENCE, scaling * WATER_BOILING_REFERENCE)
+    }
+
+    pub fn planetary_system(&self) -> PlanetarySystem<T> {
+        let stellar_mass = self.typical_stellar_mass();
+        let stellar_temperature = self.stellar_surface_temperature(stellar_mass);
+        let stellar_luminosity = self.stellar_luminosity(stellar_mass);
+        let stellar_radius = if stellar_temperature > 0.0 {
+            (stellar_luminosity / (stellar_temperature.powi(4) * (4.0 * PI * STEFAN_BOLTZMANN))).sqrt()
+        } else {
+            T::from(0.0)
+        };
+
+        // T_sup = f T★ (1 − A)^(1/4) √(R★ / 2d)  →  d(T_sup)
+        let orbit_for = |surface_temperature: T| {
+            let equilibrium_temperature = surface_temperature / GREENHOUSE_WARMING;
+            stellar_radius * 0.5 * (stellar_temperature / equilibrium_temperature).powi(2) * (1.0 - PLANET_ALBEDO).sqrt()
+        };
+        let (freezing, boiling) = self.liquid_water_window();
+        let habitable_zone_inner = orbit_for(boiling);
+        let habitable_zone_outer = orbit_for(freezing);
+        let orbit = (habitable_zone_inner * habitable_zone_outer).sqrt();
+        let surface_temperature = (freezing * boiling).sqrt();
+
+        let planet_mass = self.typical_planet_mass();
+        let planet_radius = self.planet_radius(planet_mass);
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+
+        let thermal_energy = surface_temperature * K_B;
+        let bond_to_thermal_ratio = self.h2_bond_energy() / thermal_energy;
+        let atmosphere_retention = if planet_radius > 0.0 {
+            self.laws.g * planet_mass * WATER_MOLECULE_NUCLEONS * m_proton / (planet_radius * thermal_energy)
+        } else {
+            T::from(0.0)
+        };
+
+        let tidal_locking_time = if stellar_mass > 0.0 && planet_radius > 0.0 {
+            orbit.powi(6) * INITIAL_SPIN_RATE * MOMENT_OF_INERTIA_FACTOR * planet_mass * TIDAL_QUALITY_FACTOR
+                / (self.laws.g * 3.0 * stellar_mass.powi(2) * TIDAL_LOVE_NUMBER * planet_radius.powi(3))
+        } else {
+            T::from(0.0)
+        };
+
+        PlanetarySystem {
+            planet_mass,
+            planet_radius,
+            stellar_luminosity,
+            stellar_radius,
+            habitable_zone_inner,
+            habitable_zone_outer,
+            surface_temperature,
+            bond_to_thermal_ratio,
+            atmosphere_retention,
+            tidal_locking_time,
+            stellar_lifetime: self.main_sequence_lifetime(stellar_mass),
+        }
+    }
+
+    // Planetas rocosos con agua líquida, atmósfera y tiempo suficiente para la evolución
+    pub fn habitable_planet_score(&self) -> T {
+        let system = self.planetary_system();
+        if system.planet_mass <= 0.0 || system.stellar_luminosity <= 0.0 || !system.habitable_zone_exists() {
+            return T::from(0.0);
+        }
+        if system.bond_to_thermal_ratio < MIN_BOND_TO_THERMAL_RATIO {
+            return T::from(0.0);
+        }
+
+        let retention_score = (system.atmosphere_retention / ATMOSPHERE_RETENTION_THRESHOLD).min(T::from(1.0));
+        let lifetime_score = (system.stellar_lifetime / COMPLEX_LIFE_TIME).min(T::from(1.0));
+        let rotation_score = if system.tidally_locked() { TIDAL_LOCK_PENALTY } else { 1.0 };
+
+        retention_score * lifetime_score * rotation_score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    const ASTRONOMICAL_UNIT: f64 = 1.496e11; // m
+
+    #[test]
+    fn reference_universe_has_an_earth_in_the_habitable_zone() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let system = engine.planetary_system();
+        assert!((system.planet_mass / EARTH_MASS - 1.0).abs() < 1e-6);
+        assert!((system.planet_radius / EARTH_RADIUS - 1.0).abs() < 1e-6);
+        assert!((0.4..1.0).contains(&(system.habitable_zone_inner / ASTRONOMICAL_UNIT)));
+        assert!((1.0..1.5).contains(&(system.habitable_zone_outer / ASTRONOMICAL_UNIT)));
+        assert!(!system.tidally_locked());
+        assert_eq!(engine.habitable_planet_score(), 1.0);
+    }
+
+    #[test]
+    fn strong_gravity_shrinks_stars_and_locks_planets() {
+        let mut laws = CosmicLaw::reference();
+        laws.g *= 100.0;
+        let engine = AdvancedPhysicsEngine::new(laws);
+        let system = engine.planetary_system();
+        // M ∝ G^(−3/2)
+        assert!((system.planet_mass / EARTH_MASS - 1e-3).abs() < 1e-9);
+        assert!(system.tidally_locked());
+        assert_eq!(engine.habitable_planet_score(), TIDAL_LOCK_PENALTY);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::core::models::{CosmicLaw, FitnessBreakdown, FitnessModel, COMPLEXITY_BONUS, COMPLEXITY_LEVELS};
+use crate::core::numeric::Real;
+use crate::physics::engine::AdvancedPhysicsEngine;
+
+const MIN_COSMOLOGY: f64 = 0.1; // Por debajo el universo recolapsa antes de formar estructuras
+const SCORE_MARGIN_UNIT: f64 = 0.1; // Las puntuaciones parciales se comparan con sus umbrales en décimas
+
+// Las etapas sin física definida (NaN, infinitos) no puntúan
+fn finite<T: Real>(score: T) -> T {
+    if score.is_finite() {
+        score
+    } else {
+        T::from(0.0)
+    }
+}
+
+// Mismas etapas y pesos que el fitness con umbrales, pero cada umbral es una logística:
+// ningún universo queda en una meseta plana y los optimizadores ven hacia dónde subir
+pub fn smooth_fitness_breakdown<T: Real>(laws: &CosmicLaw<T>, sharpness: f64) -> FitnessBreakdown<T> {
+    let engine = AdvancedPhysicsEngine::new(laws.clone()).with_fitness_model(FitnessModel::Smooth { sharpness });
+    let mut breakdown = FitnessBreakdown::default();
+    let score_gate = |score: T, threshold: f64| engine.gate((score - threshold) / SCORE_MARGIN_UNIT);
+
+    let dimensions = engine.extra_dimensions();
+    let light_nuclei = engine.light_nuclei();
+    let cosmology = finite(engine.cosmological_viability());
+
+    // Las puertas duras se multiplican; la primera que cae por debajo de 1/2 da el motivo
+    let hard_gates = [
+        (engine.gate(dimensions.atomic_margin()), format!("Átomos inestables en {} dimensiones (Ehrenfest)", dimensions.large_dimensions)),
+        (engine.gate(dimensions.orbital_margin()), "Órbitas gravitatorias inestables (Ehrenfest)".to_string()),
+        (engine.gate(engine.essential_decay_margin()), "Especie esencial inestable".to_string()),
+        (engine.gate(light_nuclei.deuteron_margin), "Deuterón no ligado: la nucleosíntesis no puede empezar".to_string()),
+        (engine.gate(-light_nuclei.diproton_margin), "Diprotón ligado: todo el hidrógeno arde en el Big Bang".to_string()),
+        (score_gate(cosmology, MIN_COSMOLOGY), "El universo recolapsa antes de formar estructuras".to_string()),
+    ];
+    breakdown.failure_reason = hard_gates.iter().find(|(gate, _)| *gate < 0.5).map(|(_, reason)| reason.clone());
+    let viability: T = hard_gates.iter().map(|(gate, _)| *gate).product();
+    if viability <= 0.0 {
+        return breakdown;
+    }
+
+    let history = engine.thermal_history();
+    let stellar_engine = engine.at_epoch(history.first_stars.time);
+    let stellar_death_time = history.first_stars.time + engine.main_sequence_lifetime(engine.typical_stellar_mass());
+    let remnant_engine = engine.at_epoch(stellar_death_time);
+    breakdown.neutrino_species_bbn = engine.relativistic_neutrino_species(history.weak_freeze_out.temperature);
+    breakdown.neutrino_suppression = engine.free_streaming_suppression();
+
+    breakdown.cosmology = viability * cosmology;
+    breakdown.structure = viability * finite(engine.structure_formation_score());
+    breakdown.nucleosynthesis = viability * finite(engine.at_epoch(history.nucleosynthesis.time).primordial_nucleosynthesis_success());
+    breakdown.chemistry = viability * finite(engine.chemistry_score());
+    breakdown.periodic_table = viability * finite(engine.periodic_table_score());
+
+    // Cada etapa tardía pesa lo que pesan las puertas de las anteriores
+    let early_gate = score_gate(breakdown.nucleosynthesis, 0.3) * score_gate(breakdown.chemistry, 0.3);
+    breakdown.stellar = early_gate * score_gate(breakdown.structure, 0.3) * finite(stellar_engine.stellar_formation_epoch());
+
+    let stellar_gate = score_gate(breakdown.stellar, 0.4);
+    breakdown.heavy_elements = stellar_gate * finite(remnant_engine.heavy_element_creation());
+
+    let heavy_gate = score_gate(breakdown.heavy_elements, 0.5);
+    breakdown.black_holes = heavy_gate * finite(remnant_engine.black_hole_formation_potential());
+    breakdown.planets = heavy_gate * finite(remnant_engine.habitable_planet_score());
+
+    let bonus_gate = score_gate(breakdown.black_holes, 0.8) * score_gate(breakdown.heavy_elements, 0.7);
+
+    breakdown.uncapped_fitness = breakdown.weighted_components() + bonus_gate * COMPLEXITY_BONUS;
+    breakdown.fitness = breakdown.uncapped_fitness.min(T::from(1.0));
+
+    // El nivel de complejidad sigue siendo discreto: mismos umbrales sobre las puntuaciones suaves
+    let levels: [bool; COMPLEXITY_LEVELS - 1] = [
+        breakdown.nucleosynthesis > 0.3 && breakdown.chemistry > 0.3,
+        breakdown.stellar > 0.4,
+        breakdown.heavy_elements > 0.5,
+        breakdown.black_holes > 0.6,
+        breakdown.black_holes > 0.8 && breakdown.heavy_elements > 0.7,
+        breakdown.planets > 0.5,
+    ];
+    breakdown.complexity_level = levels.iter().take_while(|reached| **reached).count() as u8;
+
+    breakdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::numeric::Dual;
+
+    #[test]
+    fn sharp_logistics_recover_the_gated_reference() {
+        let laws = CosmicLaw::reference();
+        let gated = AdvancedPhysicsEngine::new(laws.clone()).with_fitness_model(FitnessModel::Gated);
+        let smooth = smooth_fitness_breakdown(&laws, 1e3);
+        assert_eq!(smooth.complexity_level, 6);
+        assert!(smooth.failure_reason.is_none());
+        assert!((smooth.stellar - gated.stellar_formation_epoch()).abs() < 1e-3, "{} vs {}", smooth.stellar, gated.stellar_formation_epoch());
+    }
+
+    #[test]
+    fn smooth_fitness_has_a_gradient() {
+        let mut laws: CosmicLaw<Dual> = CosmicLaw::reference().lift();
+        laws.alpha_s = Dual::variable(laws.alpha_s.value);
+        let breakdown = smooth_fitness_breakdown(&laws, 5.0);
+        assert!(breakdown.fitness.derivative.is_finite());
+        assert!(breakdown.fitness.derivative != 0.0);
+    }
+}
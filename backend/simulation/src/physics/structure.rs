@@ -0,0 +1,300 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::dark_matter::hubble_rate_today;
+use crate::physics::engine::AdvancedPhysicsEngine;
+use std::f64::consts::PI;
+
+// Suelo de enfriamiento molecular en unidades de ħ²/(m_p a₀²); da ~20 K en nuestro universo
+const MOLECULAR_COOLING_FLOOR: f64 = 0.116;
+
+const NEUTRINO_RADIATION_WEIGHT: f64 = 0.2271; // (7/8)(4/11)^(4/3) por especie frente a los fotones
+const GALACTIC_SCALE_GROWTH: f64 = 40.0; // Crecimiento de Mészáros + transferencia en escalas galácticas
+const SPHERICAL_COLLAPSE_THRESHOLD: f64 = 1.686; // δ_c
+const VACUUM_ASYMPTOTIC_GROWTH: f64 = 1.437; // D(∞) / a_Λ con constante cosmológica
+const CURVATURE_ASYMPTOTIC_GROWTH: f64 = 2.5; // D(∞) / a_k en un universo abierto sin Λ
+const RECOLLAPSE_LOG_STEP: f64 = 0.01;
+const MAX_EXPANSION_FACTOR: f64 = 1e6; // Más allá Λ o la curvatura ya decidieron el destino
+
+#[derive(Debug, Clone, Copy)]
+pub struct GalaxyFormation<T = f64> {
+    pub cooling_temperature: T, // K - temperatura virial límite para enfriar por bremsstrahlung
+    pub galaxy_mass: T,         // kg
+    pub galaxy_radius: T,       // m
+    pub cloud_temperature: T,   // K - nubes frías que fragmentan en estrellas
+    pub cloud_density: T,       // kg/m³
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct VacuumDomination<T = f64> {
+    pub nonlinear_scale_factor: T, // a_nl / a_eq - las perturbaciones alcanzan δ_c
+    pub vacuum_scale_factor: T,    // a_Λ / a_eq - ρ_Λ iguala a la materia
+    pub curvature_scale_factor: T, // a_k / a_eq - la curvatura abierta iguala a la materia
+    pub collapsed_fraction: T,     // Press–Schechter con el crecimiento congelado por Λ
+}
+
+impl<T: Real> GalaxyFormation<T> {
+    fn none() -> Self {
+        Self {
+            cooling_temperature: T::from(0.0),
+            galaxy_mass: T::from(0.0),
+            galaxy_radius: T::from(0.0),
+            cloud_temperature: T::from(0.0),
+            cloud_density: T::from(0.0),
+        }
+    }
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    pub fn dark_matter_to_baryon_ratio(&self) -> T {
+        let omega_b = self.baryon_density_parameter();
+        if omega_b <= 0.0 {
+            return T::from(0.0);
+        }
+        self.dark_matter_relic().omega_dm / omega_b
+    }
+
+    // Rees–Ostriker: una nube solo colapsa en galaxia si se enfría antes de caer libremente
+    pub fn galaxy_formation(&self) -> GalaxyFormation<T> {
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        let m_electron = self.laws.mass_electron;
+        let g = self.laws.g;
+
+        if m_proton <= 0.0 || m_electron <= 0.0 || g <= 0.0 || self.alpha <= 0.0 || self.laws.baryon_to_photon_ratio <= 0.0 {
+            return GalaxyFormation::none();
+        }
+
+        let electron_rest_energy = m_electron * C.powi(2);
+        let cooling_energy = self.alpha.powi(2) * electron_rest_energy; // ~ energía de ionización
+        let cooling_temperature = cooling_energy / K_B;
+
+        let thomson_cross_section = (self.alpha * H_BAR / (m_electron * C)).powi(2) * (8.0 * PI / 3.0);
+        let bremsstrahlung_rate = self.alpha * thomson_cross_section * C * (cooling_energy * electron_rest_energy).sqrt();
+
+        // t_cool = 3kT / (n Λ) igualado a t_ff = (G ρ_total)^(-1/2)
+        let mass_loading = T::from(1.0) + self.dark_matter_to_baryon_ratio();
+        let number_density = cooling_energy.powi(2) * 9.0 * g * m_proton * mass_loading / bremsstrahlung_rate.powi(2);
+        let halo_density = number_density * m_proton * mass_loading;
+
+        let sound_speed = (cooling_energy / m_proton).sqrt();
+        let galaxy_mass = T::from((PI.powi(5) / 6.0).sqrt()) * sound_speed.powi(3) / (g.powf(1.5) * halo_density.sqrt());
+        let galaxy_radius = (galaxy_mass * 3.0 / (halo_density * (4.0 * PI))).cbrt();
+
+        // Las nubes no pueden enfriarse por debajo del fondo cósmico cuando nacen las primeras estrellas
+        let molecular_floor = cooling_temperature * MOLECULAR_COOLING_FLOOR * m_electron / m_proton;
+        let cloud_temperature = molecular_floor.max(self.first_stars_temperature());
+
+        // Las nubes frías quedan en equilibrio de presión con el gas caliente del halo
+        let cloud_density = number_density * (cooling_temperature / cloud_temperature) * m_proton;
+
+        GalaxyFormation {
+            cooling_temperature,
+            galaxy_mass,
+            galaxy_radius,
+            cloud_temperature,
+            cloud_density,
+        }
+    }
+
+    fn matter_per_photon(&self) -> T {
+        let (m_proton, _, _) = self.get_validated_hadron_masses();
+        m_proton * self.laws.baryon_to_photon_ratio * (T::from(1.0) + self.dark_matter_to_baryon_ratio()) + self.neutrino_matter_per_photon()
+    }
+
+    // Fotones más las especies de neutrinos que siguen siendo relativistas a T
+    fn radiation_species_factor(&self, temperature: T) -> T {
+        T::from(1.0) + self.relativistic_neutrino_species(temperature) * NEUTRINO_RADIATION_WEIGHT
+    }
+
+    // Igualdad materia-radiación, fijada por la materia por fotón ξ
+    pub fn matter_radiation_equality_temperature(&self) -> T {
+        let photon_number_coefficient = 2.0 * ZETA_3 / PI.powi(2);
+        let equality_for = |species_factor: T| {
+            self.matter_per_photon() * C.powi(2) * photon_number_coefficient / (species_factor * PI.powi(2) / 15.0) / K_B
+        };
+
+        // Los neutrinos que ya son no relativistas en la igualdad cuentan solo como materia
+        let all_relativistic = equality_for(self.radiation_species_factor(T::from(f64::INFINITY)));
+        equality_for(self.radiation_species_factor(all_relativistic))
+    }
+
+    pub fn matter_radiation_equality_density(&self) -> T {
+        let photon_number_coefficient = 2.0 * ZETA_3 / PI.powi(2);
+        let thermal_energy = self.matter_radiation_equality_temperature() * K_B;
+        let photon_density = (thermal_energy / (H_BAR * C)).powi(3) * photon_number_coefficient;
+        self.matter_per_photon() * photon_density
+    }
+
+    // Curvatura positiva (esférica) resta en la ecuación de Friedmann: Ω_k = −spatial_curvature
+    pub fn curvature_density_parameter(&self) -> T {
+        -self.laws.spatial_curvature
+    }
+
+    // E² = Ω_m a⁻³ + Ω_k a⁻² + Ω_Λ; el universo recolapsa si E² se anula antes de que domine Λ
+    pub fn recollapse_time(&self) -> T {
+        let omega_m = self.matter_density_parameter();
+        let omega_k = self.curvature_density_parameter();
+        let omega_lambda = self.dark_energy_density_parameter();
+        if omega_k >= 0.0 || omega_m <= 0.0 {
+            return T::from(f64::INFINITY);
+        }
+
+        let expansion_squared = |a: f64| omega_m / a.powi(3) + omega_k / a.powi(2) + omega_lambda;
+        let hubble = hubble_rate_today();
+
+        // Era de materia analítica hasta a₀, luego se integra dt = d ln a / H hasta el punto de retorno
+        let mut scale_factor: f64 = 1e-6;
+        let mut time = T::from(2.0 / 3.0 * scale_factor.powf(1.5)) / (omega_m.sqrt() * hubble);
+        let step = RECOLLAPSE_LOG_STEP;
+        while scale_factor < MAX_EXPANSION_FACTOR {
+            let next = scale_factor * step.exp();
+            let e_squared = expansion_squared(next);
+            if e_squared <= 0.0 {
+                return time * 2.0;
+            }
+            time += T::from(step) / (e_squared.sqrt() * hubble);
+            scale_factor = next;
+        }
+
+        T::from(f64::INFINITY)
+    }
+
+    // Weinberg: la estructura solo crece hasta que la energía del vacío (o la curvatura) domina la expansión
+    pub fn vacuum_domination(&self) -> VacuumDomination<T> {
+        let equality_density = self.matter_radiation_equality_density();
+        let q = self.laws.primordial_fluctuation_amplitude;
+        if equality_density <= 0.0 || q <= 0.0 {
+            return VacuumDomination {
+                nonlinear_scale_factor: T::from(f64::INFINITY),
+                vacuum_scale_factor: T::from(0.0),
+                curvature_scale_factor: T::from(0.0),
+                collapsed_fraction: T::from(0.0),
+            };
+        }
+
+        let equality_amplitude = q * GALACTIC_SCALE_GROWTH * self.free_streaming_suppression();
+        let nonlinear_scale_factor = T::from(SPHERICAL_COLLAPSE_THRESHOLD) / equality_amplitude;
+
+        let vacuum_scale_factor = if self.laws.dark_energy_density > 0.0 {
+            (equality_density / self.laws.dark_energy_density).cbrt()
+        } else {
+            T::from(f64::INFINITY)
+        };
+
+        // Un universo muy abierto se vacía: la curvatura congela el crecimiento igual que Λ
+        let omega_k = self.curvature_density_parameter();
+        let omega_m = self.matter_density_parameter();
+        let curvature_scale_factor = if omega_k > 0.0 && omega_m > 0.0 {
+            let equality_scale_factor = (omega_m * self.critical_density() / equality_density).cbrt();
+            omega_m / omega_k / equality_scale_factor
+        } else {
+            T::from(f64::INFINITY)
+        };
+
+        let asymptotic_amplitude = equality_amplitude
+            * (vacuum_scale_factor * VACUUM_ASYMPTOTIC_GROWTH).min(curvature_scale_factor * CURVATURE_ASYMPTOTIC_GROWTH);
+        let collapsed_fraction = erfc(T::from(SPHERICAL_COLLAPSE_THRESHOLD) / (asymptotic_amplitude * 2.0_f64.sqrt()));
+
+        VacuumDomination {
+            nonlinear_scale_factor,
+            vacuum_scale_factor,
+            curvature_scale_factor,
+            collapsed_fraction,
+        }
+    }
+
+    pub fn structure_formation_score(&self) -> T {
+        let galaxy = self.galaxy_formation();
+        let cloud_mass = self.calculate_jeans_mass();
+        if galaxy.galaxy_mass <= 0.0 || cloud_mass <= 0.0 {
+            return T::from(0.0);
+        }
+
+        // Una galaxia útil contiene al menos ~10⁶ nubes protoestelares
+        let fragments = galaxy.galaxy_mass / cloud_mass;
+        let fragmentation_score = (fragments.log10() / 6.0).clamp(0.0, 1.0);
+
+        // Solo la fracción de materia que colapsa antes de que domine Λ forma galaxias
+        fragmentation_score * self.vacuum_domination().collapsed_fraction
+    }
+}
+
+// Abramowitz & Stegun 7.1.26 (error < 1.5e-7)
+fn erfc<T: Real>(x: T) -> T {
+    if x < 0.0 {
+        return T::from(2.0) - erfc(-x);
+    }
+    let t = T::from(1.0) / (T::from(1.0) + x * 0.3275911);
+    let polynomial = t * (t * (t * (t * (t * 1.061405429 - 1.453152027) + 1.421413741) - 0.284496736) + 0.254829592);
+    polynomial * (-x * x).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    const SOLAR_MASS: f64 = 1.989e30;
+
+    #[test]
+    fn reference_universe_cools_galactic_halos() {
+        let galaxy = AdvancedPhysicsEngine::new(CosmicLaw::reference()).galaxy_formation();
+        // Rees–Ostriker: T ~ α² m_e c² / k ~ 3·10⁵ K y M ~ 10¹¹–10¹² M☉
+        assert!((1e5..1e6).contains(&galaxy.cooling_temperature));
+        assert!((1e10..1e13).contains(&(galaxy.galaxy_mass / SOLAR_MASS)));
+        assert!(galaxy.cloud_temperature < galaxy.cooling_temperature);
+    }
+
+    #[test]
+    fn missing_baryons_form_no_galaxies() {
+        let mut laws = CosmicLaw::reference();
+        laws.baryon_to_photon_ratio = 0.0;
+        let engine = AdvancedPhysicsEngine::new(laws);
+        assert_eq!(engine.galaxy_formation().galaxy_mass, 0.0);
+        assert_eq!(engine.structure_formation_score(), 0.0);
+    }
+
+    #[test]
+    fn larger_vacuum_energy_freezes_growth_earlier() {
+        let reference = AdvancedPhysicsEngine::new(CosmicLaw::reference()).vacuum_domination();
+        let mut laws = CosmicLaw::reference();
+        laws.dark_energy_density *= 1000.0;
+        let crowded = AdvancedPhysicsEngine::new(laws).vacuum_domination();
+
+        // ρ_Λ × 1000 adelanta a_Λ un factor 10 y se lleva buena parte del colapso
+        assert!((reference.vacuum_scale_factor / crowded.vacuum_scale_factor - 10.0).abs() < 1e-6);
+        assert!(reference.nonlinear_scale_factor < reference.vacuum_scale_factor);
+        assert!(crowded.collapsed_fraction < 0.6 * reference.collapsed_fraction, "{} frente a {}", crowded.collapsed_fraction, reference.collapsed_fraction);
+    }
+
+    #[test]
+    fn erfc_matches_tabulated_values() {
+        assert!((erfc(0.0) - 1.0).abs() < 1e-6);
+        assert!((erfc(1.0) - 0.157299).abs() < 1e-6);
+        assert!((erfc(-1.0) - 1.842701).abs() < 1e-6);
+    }
+
+    #[test]
+    fn only_a_closed_universe_recollapses() {
+        assert!(AdvancedPhysicsEngine::new(CosmicLaw::reference()).recollapse_time().is_infinite());
+
+        let recollapse = |curvature: f64| {
+            let mut laws = CosmicLaw::reference();
+            laws.spatial_curvature = curvature;
+            AdvancedPhysicsEngine::new(laws).recollapse_time()
+        };
+        assert!(recollapse(-1.0).is_infinite());
+        assert!(recollapse(1.0).is_finite());
+        assert!(recollapse(10.0) < recollapse(1.0));
+    }
+
+    #[test]
+    fn open_curvature_caps_structure_growth() {
+        let flat = AdvancedPhysicsEngine::new(CosmicLaw::reference()).vacuum_domination();
+        let mut laws = CosmicLaw::reference();
+        laws.spatial_curvature = -0.9;
+        let open = AdvancedPhysicsEngine::new(laws).vacuum_domination();
+        assert!(flat.curvature_scale_factor.is_infinite());
+        assert!(open.curvature_scale_factor.is_finite());
+        assert!(open.collapsed_fraction < flat.collapsed_fraction);
+    }
+}
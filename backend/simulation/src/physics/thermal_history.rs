@@ -0,0 +1,250 @@
+use crate::core::numeric::Real;
+use crate::physics::constants::*;
+use crate::physics::engine::AdvancedPhysicsEngine;
+use std::f64::consts::PI;
+
+const ALPHA_S_REFERENCE: f64 = 0.1181; // α_s(M_Z)
+const BETA_0_FIVE_FLAVOURS: f64 = 23.0 / 3.0;
+const QCD_TRANSITION_FRACTION: f64 = 0.71; // T_c / Λ_QCD (lattice: ~155 MeV)
+
+const FERMI_CONSTANT_REFERENCE: f64 = 1.1663787e-5; // GeV⁻²
+const WEAK_RATE_COEFFICIENT: f64 = 6.5; // Γ = C G_F² T⁵, normalizado a T_f ≈ 0.8 MeV
+const NEUTRON_LIFETIME_REFERENCE: f64 = 879.4; // s
+const BETA_ENDPOINT_REFERENCE_MEV: f64 = 0.782; // (m_n − m_p − m_e) c²
+
+const NEUTRINO_TEMPERATURE_RATIO: f64 = 0.7138; // T_ν / T_γ = (4/11)^(1/3)
+
+const FIRST_STARS_PEAK_FACTOR: f64 = 10.0; // Picos de ~3σ en minihalos, con ~3× la amplitud galáctica
+const HELIUM_BURNING_TEMPERATURE_REFERENCE: f64 = 1.5e8; // K - proceso triple-α en el Sol
+
+#[derive(Debug, Clone, Copy)]
+pub struct CosmicEpoch<T = f64> {
+    pub temperature: T, // K
+    pub time: T,        // s desde el Big Bang
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ThermalHistory<T = f64> {
+    pub qcd_transition: CosmicEpoch<T>,
+    pub weak_freeze_out: CosmicEpoch<T>,
+    pub nucleosynthesis: CosmicEpoch<T>,
+    pub recombination: CosmicEpoch<T>,
+    pub first_stars: CosmicEpoch<T>,
+    pub neutron_lifetime: T,           // s
+    pub primordial_helium_fraction: T, // Y_p
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    pub fn relativistic_degrees_of_freedom(&self, temperature: T) -> T {
+        let thermal_energy = temperature * K_B;
+        let neutrinos = self.neutrino_degrees_of_freedom(temperature);
+        if temperature > self.qcd_transition_temperature() {
+            T::from(56.5) + neutrinos
+        } else if thermal_energy > self.laws.mass_muon * C.powi(2) {
+            T::from(12.0) + neutrinos // γ, e±, μ± y π
+        } else if thermal_energy > self.laws.mass_electron * C.powi(2) {
+            T::from(5.5) + neutrinos
+        } else {
+            T::from(2.0) + neutrinos * NEUTRINO_TEMPERATURE_RATIO.powi(4) // Neutrinos desacoplados más fríos que los fotones
+        }
+    }
+
+    // H = A (kT)² en la era de radiación
+    fn radiation_expansion_coefficient(&self, temperature: T) -> T {
+        let g_star = self.relativistic_degrees_of_freedom(temperature);
+        (self.laws.g * (8.0 * PI) * g_star * PI.powi(2) / (90.0 * H_BAR.powi(3) * C.powi(5))).sqrt()
+    }
+
+    pub fn time_at_temperature(&self, temperature: T) -> T {
+        if temperature <= 0.0 || self.laws.g <= 0.0 {
+            return T::from(f64::INFINITY);
+        }
+
+        let equality_temperature = self.matter_radiation_equality_temperature();
+        if temperature >= equality_temperature {
+            let hubble_rate = self.radiation_expansion_coefficient(temperature) * (temperature * K_B).powi(2);
+            T::from(1.0) / (hubble_rate * 2.0)
+        } else {
+            let matter_density = self.matter_radiation_equality_density() * (temperature / equality_temperature).powi(3);
+            T::from(2.0) / ((self.laws.g * (8.0 * PI) * matter_density / 3.0).sqrt() * 3.0)
+        }
+    }
+
+    pub fn temperature_at_time(&self, time: T) -> T {
+        if time <= 0.0 || self.laws.g <= 0.0 {
+            return T::from(f64::INFINITY);
+        }
+
+        let equality_temperature = self.matter_radiation_equality_temperature();
+        if time <= self.time_at_temperature(equality_temperature) {
+            // g* depende de T: basta con un par de iteraciones de punto fijo
+            let mut temperature = equality_temperature;
+            for _ in 0..4 {
+                let coefficient = self.radiation_expansion_coefficient(temperature);
+                temperature = (T::from(1.0) / (coefficient * 2.0 * time)).sqrt() / K_B;
+            }
+            temperature
+        } else {
+            let matter_density = T::from(1.0) / (self.laws.g * (6.0 * PI) * time.powi(2));
+            equality_temperature * (matter_density / self.matter_radiation_equality_density()).cbrt()
+        }
+    }
+
+    // Λ_QCD corre con α_s(M_Z) a un lazo
+    pub fn lambda_qcd_gev(&self) -> T {
+        let alpha_s = self.laws.alpha_s;
+        if alpha_s <= 0.0 {
+            return T::from(0.0);
+        }
+        let exponent = (T::from(1.0 / ALPHA_S_REFERENCE) - T::from(1.0) / alpha_s) * (2.0 * PI / BETA_0_FIVE_FLAVOURS);
+        exponent.exp() * LAMBDA_QCD_GEV
+    }
+
+    // La transición ocurre a ~0.7 Λ_QCD
+    pub fn qcd_transition_temperature(&self) -> T {
+        self.lambda_qcd_gev() * QCD_TRANSITION_FRACTION * GEV_TO_JOULE / K_B
+    }
+
+    // M_W = g v / 2 con g² = 4π α_w cuando el vev es un parámetro
+    pub fn w_boson_mass_gev(&self) -> T {
+        match self.laws.higgs_sector {
+            Some(higgs) => (self.laws.alpha_w * (4.0 * PI)).sqrt() * higgs.higgs_vev / 2.0,
+            None => T::from(W_BOSON_MASS_GEV),
+        }
+    }
+
+    // M_Z = M_W / cos θ_W con sin² θ_W = α / α_w
+    pub fn z_boson_mass_gev(&self) -> T {
+        let sin2_weinberg = self.alpha / self.laws.alpha_w;
+        if !(0.0..1.0).contains(&sin2_weinberg.value()) {
+            return T::from(f64::INFINITY);
+        }
+        self.w_boson_mass_gev() / (T::from(1.0) - sin2_weinberg).sqrt()
+    }
+
+    pub fn fermi_constant(&self) -> T {
+        let w_mass = self.w_boson_mass_gev();
+        if w_mass <= 0.0 {
+            return T::from(0.0);
+        }
+        self.laws.alpha_w * PI / (w_mass.powi(2) * 2.0_f64.sqrt())
+    }
+
+    // Las reacciones n ↔ p se congelan cuando Γ_weak = C G_F² T⁵ cae por debajo de H
+    pub fn weak_freeze_out_temperature(&self) -> T {
+        let fermi_constant_si = self.fermi_constant() / GEV_TO_JOULE.powi(2); // J⁻²
+        if fermi_constant_si <= 0.0 {
+            return T::from(f64::INFINITY);
+        }
+
+        let mut temperature = T::from(1e10);
+        for _ in 0..4 {
+            let coefficient = self.radiation_expansion_coefficient(temperature);
+            let thermal_energy = (coefficient * H_BAR / (fermi_constant_si.powi(2) * WEAK_RATE_COEFFICIENT)).cbrt();
+            temperature = thermal_energy / K_B;
+        }
+        temperature
+    }
+
+    // τ_n ∝ 1 / (G_F² E₀⁵); un neutrón sin energía para decaer es estable
+    pub fn neutron_lifetime(&self) -> T {
+        let (m_proton, m_neutron, _) = self.get_validated_hadron_masses();
+        let endpoint_mev = (m_neutron - m_proton - self.laws.mass_electron) * C.powi(2) / MEV_TO_JOULE;
+        let fermi_constant = self.fermi_constant();
+        if endpoint_mev <= 0.0 || fermi_constant <= 0.0 {
+            return T::from(f64::INFINITY);
+        }
+
+        (T::from(FERMI_CONSTANT_REFERENCE) / fermi_constant).powi(2)
+            * NEUTRON_LIFETIME_REFERENCE
+            * (T::from(BETA_ENDPOINT_REFERENCE_MEV) / endpoint_mev).powi(5)
+    }
+
+    // Saha para el hidrógeno con fracción de ionización 1/2
+    pub fn recombination_temperature(&self) -> T {
+        let eta = self.laws.baryon_to_photon_ratio;
+        let electron_rest_energy = self.laws.mass_electron * C.powi(2);
+        let rydberg = self.rydberg_energy();
+        if eta <= 0.0 || rydberg <= 0.0 {
+            return T::from(0.0);
+        }
+
+        let mut thermal_energy = rydberg / 40.0;
+        for _ in 0..20 {
+            let log_ratio = (T::from(2.0) / (eta * 3.84)).ln() + (electron_rest_energy / thermal_energy).ln() * 1.5;
+            thermal_energy = rydberg / log_ratio.max(T::from(1.0));
+        }
+        thermal_energy / K_B
+    }
+
+    // La energía de Gamow del triple-α escala como α² μ c²; μ es la masa reducida empírica He-Be
+    pub fn helium_burning_temperature(&self) -> T {
+        (self.alpha / ALPHA_EMPIRICAL).powi(2) * HELIUM_BURNING_TEMPERATURE_REFERENCE
+    }
+
+    // Las primeras estrellas nacen en los picos raros que colapsan antes que las galaxias típicas
+    pub fn first_stars_temperature(&self) -> T {
+        let first_collapse_scale_factor = self.vacuum_domination().nonlinear_scale_factor / FIRST_STARS_PEAK_FACTOR;
+        self.matter_radiation_equality_temperature() / first_collapse_scale_factor
+    }
+
+    pub fn thermal_history(&self) -> ThermalHistory<T> {
+        let epoch = |temperature: T| CosmicEpoch {
+            temperature,
+            time: self.time_at_temperature(temperature),
+        };
+
+        let weak_freeze_out = epoch(self.weak_freeze_out_temperature());
+        let nucleosynthesis = epoch(self.deuterium_bottleneck_temperature());
+
+        // Los neutrones congelados a T_f decaen hasta que el deuterio deja de fotodisociarse
+        let (m_proton, m_neutron, _) = self.get_validated_hadron_masses();
+        let mass_gap_energy = (m_neutron - m_proton) * C.powi(2);
+        let frozen_neutron_fraction = T::from(1.0) / (T::from(1.0) + (mass_gap_energy / (weak_freeze_out.temperature * K_B)).exp());
+        let neutron_lifetime = self.neutron_lifetime();
+        let elapsed = (nucleosynthesis.time - weak_freeze_out.time).max(T::from(0.0));
+        let surviving_neutron_fraction = frozen_neutron_fraction * (-elapsed / neutron_lifetime).exp();
+
+        ThermalHistory {
+            qcd_transition: epoch(self.qcd_transition_temperature()),
+            weak_freeze_out,
+            nucleosynthesis,
+            recombination: epoch(self.recombination_temperature()),
+            first_stars: epoch(self.first_stars_temperature()),
+            neutron_lifetime,
+            primordial_helium_fraction: (surviving_neutron_fraction * 2.0).min(T::from(1.0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    #[test]
+    fn reference_epochs_follow_standard_cosmology() {
+        let history = AdvancedPhysicsEngine::new(CosmicLaw::reference()).thermal_history();
+        assert!((0.5..3.0).contains(&history.weak_freeze_out.time));
+        assert!((100.0..1000.0).contains(&history.nucleosynthesis.time));
+        assert!((3000.0..4500.0).contains(&history.recombination.temperature));
+        assert!((0.22..0.30).contains(&history.primordial_helium_fraction), "Y_p = {}", history.primordial_helium_fraction);
+    }
+
+    #[test]
+    fn time_and_temperature_are_inverse() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        // A ambos lados de la igualdad materia-radiación
+        for temperature in [1e9, 3000.0] {
+            let roundtrip = engine.temperature_at_time(engine.time_at_temperature(temperature));
+            assert!((roundtrip / temperature - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn neutron_without_decay_energy_is_stable() {
+        let mut laws = CosmicLaw::reference();
+        laws.mass_electron *= 3.0;
+        assert!(AdvancedPhysicsEngine::new(laws).neutron_lifetime().is_infinite());
+    }
+}
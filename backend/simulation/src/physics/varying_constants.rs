@@ -0,0 +1,90 @@
+use crate::core::numeric::Real;
+use crate::physics::engine::AdvancedPhysicsEngine;
+
+const PRESENT_AGE: f64 = 13.8e9 * 31557600.0; // s - las leyes muestreadas valen hoy
+const DRIFT_PER_LOG_RATE: f64 = 0.01; // d ln α / d ln t por unidad de ln(temporal_evolution_rate)
+const MIN_EVOLUTION_RATE: f64 = 1e-3;
+
+const OKLO_LOOKBACK: f64 = 1.8e9 * 31557600.0; // s - reactor natural de Gabón
+const QUASAR_ABSORPTION_AGE: f64 = 3.3e9 * 31557600.0; // s - sistemas de absorción a z ≈ 2
+
+#[derive(Debug, Clone, Copy)]
+pub struct VaryingConstantBounds<T = f64> {
+    pub oklo_alpha_shift: T,     // |Δα/α| hace 1.8 Gyr
+    pub quasar_alpha_shift: T,   // |Δα/α| a z ≈ 2
+    pub gravity_drift_rate: T,   // |Ġ/G| hoy, yr⁻¹
+}
+
+impl<T: Real> AdvancedPhysicsEngine<T> {
+    // α ∝ t^s y, à la Dirac, G ∝ t^(−s), con s = 0.01 ln(temporal_evolution_rate)
+    pub fn constant_drift_exponent(&self) -> T {
+        self.laws.temporal_evolution_rate.max(T::from(MIN_EVOLUTION_RATE)).ln() * DRIFT_PER_LOG_RATE
+    }
+
+    // (α(t)/α₀, G(t)/G₀)
+    pub fn constant_ratios_at(&self, time: T) -> (T, T) {
+        if !time.is_finite() || time <= 0.0 {
+            return (T::from(1.0), T::from(1.0));
+        }
+        let evolution = (time / PRESENT_AGE).pow(self.constant_drift_exponent());
+        (evolution, evolution.recip())
+    }
+
+    // El mismo universo con las constantes que tenía en el instante dado
+    pub fn at_epoch(&self, time: T) -> Self {
+        let (alpha_ratio, g_ratio) = self.constant_ratios_at(time);
+        let mut laws = self.laws.clone();
+        laws.e *= alpha_ratio.sqrt();
+        laws.g *= g_ratio;
+
+        Self {
+            laws,
+            alpha: self.alpha * alpha_ratio,
+            alpha_s_reference: self.alpha_s_reference,
+            reference_scale: self.reference_scale,
+            compactification_radii: self.compactification_radii,
+            fitness_model: self.fitness_model,
+        }
+    }
+
+    pub fn varying_constant_bounds(&self) -> VaryingConstantBounds<T> {
+        let (oklo_ratio, _) = self.constant_ratios_at(T::from(PRESENT_AGE - OKLO_LOOKBACK));
+        let (quasar_ratio, _) = self.constant_ratios_at(T::from(QUASAR_ABSORPTION_AGE));
+
+        VaryingConstantBounds {
+            oklo_alpha_shift: (T::from(1.0) - oklo_ratio).abs(),
+            quasar_alpha_shift: (T::from(1.0) - quasar_ratio).abs(),
+            gravity_drift_rate: self.constant_drift_exponent().abs() / (PRESENT_AGE / 31557600.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CosmicLaw;
+
+    #[test]
+    fn constant_laws_do_not_drift() {
+        let engine = AdvancedPhysicsEngine::new(CosmicLaw::reference());
+        let earlier = engine.at_epoch(PRESENT_AGE / 1000.0);
+        assert_eq!(engine.constant_drift_exponent(), 0.0);
+        assert!((earlier.alpha / engine.alpha - 1.0).abs() < 1e-12);
+        assert_eq!(earlier.laws.g, engine.laws.g);
+        assert_eq!(engine.varying_constant_bounds().oklo_alpha_shift, 0.0);
+    }
+
+    #[test]
+    fn fast_evolution_drifts_alpha_up_and_gravity_down() {
+        let mut laws = CosmicLaw::reference();
+        laws.temporal_evolution_rate = 10.0;
+        let engine = AdvancedPhysicsEngine::new(laws);
+        let earlier = engine.at_epoch(PRESENT_AGE / 10.0);
+
+        // s = 0.01 ln 10: hace un orden de magnitud en t, α era 10^(−s) veces menor y G 10^s veces mayor
+        let factor = 10.0_f64.powf(engine.constant_drift_exponent());
+        assert!((engine.alpha / earlier.alpha - factor).abs() < 1e-9);
+        assert!((earlier.laws.g / engine.laws.g - factor).abs() < 1e-9);
+        assert!(engine.varying_constant_bounds().oklo_alpha_shift > 1e-4);
+    }
+}
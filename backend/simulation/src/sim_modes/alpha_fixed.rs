@@ -8,6 +8,7 @@ use std::f64::consts::PI;
 
 pub fn run_alpha_fixed_mode(alpha_denominator: u32, samples: u32) -> Result<(), Box<dyn Error>> {
     let mut rng = thread_rng();
+    let reference = CosmicLaw::reference();
     let mut wtr = csv::Writer::from_path("alpha_fixed_analysis.csv")?;
 
     wtr.write_record(&[
@@ -38,35 +39,8 @@ pub fn run_alpha_fixed_mode(alpha_denominator: u32, samples: u32) -> Result<(),
         let derived_e =
             (target_alpha * 4.0 * PI * epsilon0_variant * hbar_variant * c_variant).sqrt();
 
-        let test_universe = CosmicLaw {
-            g: rng.gen_range(6.674e-11..6.674e-10),
-            e: derived_e,
-            alpha_s: rng.gen_range(0.05..2.0),
-            alpha_w: rng.gen_range(1.0e-9..1.0e-4),
-
-            mass_up_quark: rng.gen_range(1.0e-30..6.0e-30),
-            mass_down_quark: rng.gen_range(1.0e-30..1.3e-29),
-            mass_electron: rng.gen_range(1.0e-31..1.0e-30),
-
-            mass_charm_quark: rng.gen_range(1.0e-29..1.0e-27),
-            mass_strange_quark: rng.gen_range(1.0e-29..1.0e-28),
-            mass_muon: rng.gen_range(1.0e-29..1.0e-27),
-
-            mass_top_quark: rng.gen_range(1.0e-28..1.0e-25),
-            mass_bottom_quark: rng.gen_range(1.0e-28..1.0e-27),
-            mass_tauon: rng.gen_range(1.0e-28..1.0e-26),
-
-            spatial_curvature: rng.gen_range(-1.0..1.0),
-            dimensional_ratios: [
-                rng.gen_range(0.5..2.0),
-                rng.gen_range(0.5..2.0),
-                rng.gen_range(0.5..2.0),
-                rng.gen_range(0.5..2.0),
-            ],
-            temporal_evolution_rate: rng.gen_range(0.1..10.0),
-            dark_energy_density: rng.gen_range(0.0..2.0e-29),
-            dark_matter_coupling: rng.gen_range(0.0..1.0),
-        };
+        let mut test_universe = CosmicLaw::sample_prior(&reference, &mut rng);
+        test_universe.e = derived_e;
 
         let (fitness, complexity_level) = calculate_enhanced_fitness(&test_universe);
 
@@ -0,0 +1,146 @@
+use crate::physics::constants::{ELEMENTARY_CHARGE, EV_TO_KG, MEV_TO_KG, SOLAR_LUMINOSITY};
+use crate::physics::engine::{AdvancedPhysicsEngine, calculate_fitness_breakdown, selected_fitness_model};
+use crate::sim_modes::validate::load_universe;
+use std::error::Error;
+
+pub fn run_evaluation_mode(universe_path: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let laws = load_universe(universe_path)?;
+
+    println!("🔭 EVALUANDO UNIVERSO: {}", universe_path.unwrap_or("referencia"));
+    println!("{}", "=".repeat(60));
+
+    let engine = AdvancedPhysicsEngine::new(laws.clone()).with_fitness_model(selected_fitness_model());
+    let breakdown = calculate_fitness_breakdown(&laws);
+
+    println!("⚛️ α = 1/{:.3}", 1.0 / engine.alpha);
+
+    let (m_proton, m_neutron, m_pion) = engine.get_validated_hadron_masses();
+    println!("\n⚖️ MASAS");
+    if let Some(higgs) = laws.higgs_sector {
+        println!("   vev de Higgs: {:.2} GeV", higgs.higgs_vev);
+    }
+    println!("   Electrón: {:.4} MeV", engine.laws.mass_electron / MEV_TO_KG);
+    println!("   Pion: {:.2} MeV", m_pion / MEV_TO_KG);
+    println!("   m_n − m_p: {:.4} MeV", (m_neutron - m_proton) / MEV_TO_KG);
+    println!("   W: {:.2} GeV | Z: {:.2} GeV", engine.w_boson_mass_gev(), engine.z_boson_mass_gev());
+
+    let bounds = engine.varying_constant_bounds();
+    println!("\n⏳ CONSTANTES VARIABLES (α ∝ t^{:.4})", engine.constant_drift_exponent());
+    println!("   Oklo |Δα/α|: {:.2e} | Cuásares |Δα/α|: {:.2e}", bounds.oklo_alpha_shift, bounds.quasar_alpha_shift);
+    println!("   |Ġ/G|: {:.2e} yr⁻¹", bounds.gravity_drift_rate);
+
+    let dimensions = engine.extra_dimensions();
+    println!("\n📐 DIMENSIONES");
+    println!(
+        "   Radios de compactificación: {}",
+        dimensions.compactification_radii.map(|radius| format!("{:.2e} m", radius)).join(", ")
+    );
+    println!("   Dimensiones grandes a escala atómica: {}", dimensions.large_dimensions);
+    println!("   Átomos: {} | Órbitas: {}", stability_mark(dimensions.atoms_stable), stability_mark(dimensions.orbits_stable));
+    println!("   G_eff / G a escala estelar: {:.4}", dimensions.gravity_enhancement);
+
+    let vacuum = engine.vacuum_domination();
+    println!("\n🌀 GEOMETRÍA Y EXPANSIÓN");
+    println!(
+        "   Ω_m: {:.3} | Ω_Λ: {:.3} | Ω_k: {:.4}",
+        engine.matter_density_parameter(),
+        engine.dark_energy_density_parameter(),
+        engine.curvature_density_parameter()
+    );
+    println!("   Recolapso: {:.3e} s", engine.recollapse_time());
+    println!(
+        "   a_nl / a_eq: {:.3e} | a_Λ / a_eq: {:.3e} | a_k / a_eq: {:.3e}",
+        vacuum.nonlinear_scale_factor, vacuum.vacuum_scale_factor, vacuum.curvature_scale_factor
+    );
+    println!("   Fracción colapsada: {:.3e}", vacuum.collapsed_fraction);
+
+    let neutrinos = engine.neutrino_sector();
+    println!("\n👻 NEUTRINOS");
+    println!("   Σ m_ν: {:.4} eV | N_ν: {:.2}", neutrinos.total_mass / EV_TO_KG, engine.laws.neutrino_species);
+    println!("   N_eff en el congelamiento débil: {:.2}", neutrinos.effective_species_bbn);
+    println!("   Ω_ν: {:.3e} | f_ν: {:.3e}", neutrinos.density_parameter, neutrinos.matter_fraction);
+    println!("   Supresión por flujo libre: {:.4}", neutrinos.free_streaming_suppression);
+
+    let spectrum = engine.baryon_spectrum();
+    println!("\n🧱 ESPECTRO BARIÓNICO ({})", engine.baryon_category());
+    for baryon in &spectrum.baryons {
+        println!("   {:<4} {:>9.1} MeV (Q = {:+})", baryon.name, baryon.mass / MEV_TO_KG, baryon.charge);
+    }
+    println!("   Barión estable: {}", spectrum.lightest().name);
+
+    let decays = engine.decay_analysis();
+    println!("\n💥 DESINTEGRACIONES ({} canales abiertos)", decays.channels.len());
+    println!("   Especies estables: {}", decays.stable.join(", "));
+    for channel in decays.channels.iter().filter(|c| ["n", "μ⁻", "e⁻", "p", "H"].contains(&c.parent)).take(8) {
+        println!("   {}", channel);
+    }
+
+    let chemistry = engine.chemistry();
+    println!("\n🔬 QUÍMICA");
+    println!("   Radio de Bohr: {:.3e} m", chemistry.bohr_radius);
+    println!("   Energía de Rydberg: {:.3} eV", chemistry.rydberg_energy / ELEMENTARY_CHARGE);
+    println!("   Enlace H₂: {:.3} eV", chemistry.h2_bond_energy / ELEMENTARY_CHARGE);
+    println!("   Superficie estelar típica: {:.0} K", chemistry.stellar_surface_temperature);
+
+    let light_nuclei = engine.light_nuclei();
+    println!("\n☢️ NÚCLEOS LIGEROS");
+    println!("   Alcance nuclear: {:.3} fm", light_nuclei.nuclear_range * 1e15);
+    println!("   Deuterón: {} ({:.3} MeV)", stability_mark(light_nuclei.deuteron_bound()), light_nuclei.deuteron_binding);
+    println!("   Diprotón inestable: {}", stability_mark(!light_nuclei.diproton_bound()));
+
+    let table = engine.periodic_table();
+    println!("\n🧪 TABLA PERIÓDICA");
+    println!("   Núcleos estables: {}", table.stable_nuclei);
+    println!("   Elementos estables: {} (el más pesado Z = {})", table.stable_elements, table.heaviest_element);
+    println!(
+        "   C: {} | N: {} | O: {} | Fe: {}",
+        stability_mark(table.carbon_stable),
+        stability_mark(table.nitrogen_stable),
+        stability_mark(table.oxygen_stable),
+        stability_mark(table.iron_stable)
+    );
+
+    let system = engine.planetary_system();
+    let (freezing, boiling) = engine.liquid_water_window();
+    println!("\n🌍 PLANETAS");
+    println!("   Estrella: {:.3e} L☉ | R★ = {:.3e} m", system.stellar_luminosity / SOLAR_LUMINOSITY, system.stellar_radius);
+    println!("   Planeta rocoso: {:.3e} kg | R = {:.3e} m", system.planet_mass, system.planet_radius);
+    println!("   Agua líquida: {:.0}–{:.0} K (superficie {:.0} K)", freezing, boiling, system.surface_temperature);
+    println!(
+        "   Zona habitable: {:.3e}–{:.3e} m ({})",
+        system.habitable_zone_inner,
+        system.habitable_zone_outer,
+        stability_mark(system.habitable_zone_exists())
+    );
+    println!("   D₀(H₂) / kT superficie: {:.1}", system.bond_to_thermal_ratio);
+    println!("   Retención atmosférica (λ agua): {:.3e}", system.atmosphere_retention);
+    println!(
+        "   Acoplamiento de marea: {:.3e} s (vida estelar {:.3e} s) {}",
+        system.tidal_locking_time,
+        system.stellar_lifetime,
+        if system.tidally_locked() { "🔒" } else { "🔄" }
+    );
+
+    println!("\n📊 DESGLOSE DE FITNESS");
+    println!("   Cosmología:       {:.3}", breakdown.cosmology);
+    println!("   Estructura:       {:.3}", breakdown.structure);
+    println!("   Nucleosíntesis:   {:.3}", breakdown.nucleosynthesis);
+    println!("   Química:          {:.3}", breakdown.chemistry);
+    println!("   Tabla periódica:  {:.3}", breakdown.periodic_table);
+    println!("   Estrellas:        {:.3}", breakdown.stellar);
+    println!("   Elementos pesados:{:.3}", breakdown.heavy_elements);
+    println!("   Agujeros negros:  {:.3}", breakdown.black_holes);
+    println!("   Planetas:         {:.3}", breakdown.planets);
+    println!("   N_eff (BBN):      {:.3}", breakdown.neutrino_species_bbn);
+    println!("   Supresión ν:      {:.3}", breakdown.neutrino_suppression);
+    if let Some(reason) = &breakdown.failure_reason {
+        println!("\n💀 {}", reason);
+    }
+    println!("\n🏆 Fitness: {:.4} | Nivel de complejidad: {}", breakdown.fitness, breakdown.complexity_level);
+
+    Ok(())
+}
+
+fn stability_mark(stable: bool) -> &'static str {
+    if stable { "✅" } else { "❌" }
+}
@@ -1,12 +1,35 @@
 use crate::core::models::CosmicLaw;
-use crate::physics::engine::calculate_enhanced_fitness;
+use crate::core::parameters::LawParameter;
+use crate::physics::constants::{EV_TO_KG, GEV_TO_KG};
+use crate::physics::engine::{AdvancedPhysicsEngine, calculate_enhanced_fitness};
+use crate::utils::curvature_class;
 use std::error::Error;
 use std::collections::HashMap;
 use rand::prelude::*;
 use csv;
 
+// La premisa geométrica explora más allá del prior compartido: dimensiones, curvatura y contenido cósmico.
+// Mismas escalas que LAW_PARAMETERS con los rangos que este modo siempre muestreó
+const GEOMETRIC_RANGES: [LawParameter; 14] = [
+    LawParameter::linear("spatial_curvature", -2.0, 2.0),
+    LawParameter::linear("dimensional_ratio_0", 0.1, 5.0),
+    LawParameter::linear("dimensional_ratio_1", 0.1, 5.0),
+    LawParameter::linear("dimensional_ratio_2", 0.1, 5.0),
+    LawParameter::linear("dimensional_ratio_3", 0.1, 5.0),
+    LawParameter::logarithmic("temporal_evolution_rate", 0.01, 100.0),
+    LawParameter::linear("dark_energy_density", 0.0, 5.0e-29),
+    LawParameter::linear("dark_matter_coupling", 0.0, 2.0),
+    LawParameter::logarithmic("dark_matter_mass", 0.56 * GEV_TO_KG, 5.6e4 * GEV_TO_KG),
+    LawParameter::logarithmic("primordial_fluctuation_amplitude", 1.0e-7, 1.0e-3),
+    LawParameter::logarithmic("baryon_to_photon_ratio", 1.0e-12, 1.0e-8),
+    LawParameter::linear("neutrino_mass_1", 0.0, 11.0 * EV_TO_KG),
+    LawParameter::linear("neutrino_mass_2", 0.0, 11.0 * EV_TO_KG),
+    LawParameter::linear("neutrino_mass_3", 0.0, 11.0 * EV_TO_KG),
+];
+
 pub fn run_geometric_mode(samples: u32) -> Result<(), Box<dyn Error>> {
     let mut rng = thread_rng();
+    let reference = CosmicLaw::reference();
     let mut wtr = csv::Writer::from_path("geometric_analysis.csv")?;
 
     wtr.write_record(&[
@@ -17,6 +40,8 @@ pub fn run_geometric_mode(samples: u32) -> Result<(), Box<dyn Error>> {
         "dim_ratio_3",
         "dim_ratio_4",
         "temporal_rate",
+        "large_dimensions",
+        "gravity_enhancement",
         "fitness",
         "complexity_level",
     ])?;
@@ -24,54 +49,29 @@ pub fn run_geometric_mode(samples: u32) -> Result<(), Box<dyn Error>> {
     println!("📐 PREMISA 6: Análisis Geométrico de Universos 5D");
     println!("🌌 Explorando cómo la geometría 5D afecta la viabilidad 4D");
 
-    let mut geometric_patterns = HashMap::new();
+    let mut geometric_patterns: HashMap<String, (u32, u32)> = HashMap::new();
+    let mut dimension_counts: HashMap<u8, u32> = HashMap::new();
+    let mut unstable_orbits = 0;
 
     for sample_id in 0..samples {
-        let test_universe = CosmicLaw {
-            g: rng.gen_range(6.674e-11..6.674e-10),
-            e: rng.gen_range(0.5e-19..2.5e-19),
-            alpha_s: rng.gen_range(0.05..2.0),
-            alpha_w: rng.gen_range(1.0e-9..1.0e-4),
-
-            mass_up_quark: rng.gen_range(1.0e-30..6.0e-30),
-            mass_down_quark: rng.gen_range(1.0e-30..1.3e-29),
-            mass_electron: rng.gen_range(1.0e-31..1.0e-30),
-
-            mass_charm_quark: rng.gen_range(1.0e-29..1.0e-27),
-            mass_strange_quark: rng.gen_range(1.0e-29..1.0e-28),
-            mass_muon: rng.gen_range(1.0e-29..1.0e-27),
-
-            mass_top_quark: rng.gen_range(1.0e-28..1.0e-25),
-            mass_bottom_quark: rng.gen_range(1.0e-28..1.0e-27),
-            mass_tauon: rng.gen_range(1.0e-28..1.0e-26),
-
-            spatial_curvature: rng.gen_range(-2.0..2.0),
-            dimensional_ratios: [
-                rng.gen_range(0.1..5.0),
-                rng.gen_range(0.1..5.0),
-                rng.gen_range(0.1..5.0),
-                rng.gen_range(0.1..5.0),
-            ],
-            temporal_evolution_rate: rng.gen_range(0.01..100.0),
-            dark_energy_density: rng.gen_range(0.0..5.0e-29),
-            dark_matter_coupling: rng.gen_range(0.0..2.0),
-        };
+        let prior_universe = CosmicLaw::sample_prior(&reference, &mut rng);
+        let test_universe = CosmicLaw::sample_ranges(&prior_universe, &GEOMETRIC_RANGES, &mut rng);
 
         let (fitness, complexity_level) = calculate_enhanced_fitness(&test_universe);
+        let dimensions = AdvancedPhysicsEngine::new(test_universe.clone()).extra_dimensions();
 
-        let curvature_class = if test_universe.spatial_curvature < -0.5 {
-            "Hyperbolic"
-        } else if test_universe.spatial_curvature > 0.5 {
-            "Spherical"
-        } else {
-            "Flat"
-        };
+        *dimension_counts.entry(dimensions.large_dimensions).or_insert(0) += 1;
+        if !dimensions.orbits_stable {
+            unstable_orbits += 1;
+        }
 
-        *geometric_patterns
-            .entry(curvature_class.to_string())
-            .or_insert(0) += 1;
+        let (total, viable) = geometric_patterns
+            .entry(curvature_class(test_universe.spatial_curvature).to_string())
+            .or_insert((0, 0));
+        *total += 1;
 
         if fitness > 0.1 {
+            *viable += 1;
             wtr.write_record(&[
                 sample_id.to_string(),
                 format!("{:.4}", test_universe.spatial_curvature),
@@ -80,6 +80,8 @@ pub fn run_geometric_mode(samples: u32) -> Result<(), Box<dyn Error>> {
                 format!("{:.4}", test_universe.dimensional_ratios[2]),
                 format!("{:.4}", test_universe.dimensional_ratios[3]),
                 format!("{:.4}", test_universe.temporal_evolution_rate),
+                dimensions.large_dimensions.to_string(),
+                format!("{:.4e}", dimensions.gravity_enhancement),
                 format!("{:.6}", fitness),
                 complexity_level.to_string(),
             ])?;
@@ -89,11 +91,55 @@ pub fn run_geometric_mode(samples: u32) -> Result<(), Box<dyn Error>> {
     wtr.flush()?;
 
     println!("\n📊 === ANÁLISIS DE PATRONES GEOMÉTRICOS ===");
-    for (geometry, count) in geometric_patterns {
-        println!("🔷 Geometría {}: {} universos", geometry, count);
+    for (geometry, (count, viable)) in geometric_patterns {
+        println!(
+            "🔷 Geometría {}: {} universos, {} viables ({:.1}%)",
+            geometry,
+            count,
+            viable,
+            100.0 * viable as f64 / count as f64
+        );
+    }
+
+    let mut dimension_keys: Vec<_> = dimension_counts.keys().copied().collect();
+    dimension_keys.sort();
+    for large_dimensions in dimension_keys {
+        let stability = if large_dimensions == 3 { "átomos estables" } else { "átomos colapsan" };
+        println!(
+            "📏 {} dimensiones grandes: {} universos ({})",
+            large_dimensions, dimension_counts[&large_dimensions], stability
+        );
     }
+    println!("🪐 Órbitas inestables: {} de {} universos", unstable_orbits, samples);
 
     println!("💫 Análisis completo guardado en 'geometric_analysis.csv'");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    // Órbitas inestables y G de Kaluza–Klein solo aparecen con los radios exploratorios
+    #[test]
+    fn exploratory_ranges_reach_beyond_the_shared_prior() {
+        let reference = CosmicLaw::reference();
+        let mut rng = StdRng::seed_from_u64(5);
+        let (mut prior_unstable, mut exploratory_unstable) = (0, 0);
+        for _ in 0..300 {
+            let prior_universe = CosmicLaw::sample_prior(&reference, &mut rng);
+            if !AdvancedPhysicsEngine::new(prior_universe.clone()).extra_dimensions().orbits_stable {
+                prior_unstable += 1;
+            }
+            let exploratory = CosmicLaw::sample_ranges(&prior_universe, &GEOMETRIC_RANGES, &mut rng);
+            assert_eq!(exploratory.mass_electron, prior_universe.mass_electron);
+            if !AdvancedPhysicsEngine::new(exploratory).extra_dimensions().orbits_stable {
+                exploratory_unstable += 1;
+            }
+        }
+        assert_eq!(prior_unstable, 0);
+        assert!(exploratory_unstable > 0);
+    }
+}
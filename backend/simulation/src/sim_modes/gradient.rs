@@ -0,0 +1,146 @@
+use crate::core::models::{CosmicLaw, FitnessBreakdown, FITNESS_COMPONENTS};
+use crate::core::numeric::Dual;
+use crate::core::parameters::{LawParameter, ParameterScale, LAW_PARAMETERS};
+use crate::physics::engine::calculate_fitness_breakdown;
+use crate::sim_modes::validate::load_universe;
+use crate::utils::analyze_universe_type;
+use std::error::Error;
+use csv;
+
+struct FieldGradient {
+    parameter: &'static LawParameter,
+    value: f64,
+    breakdown: FitnessBreakdown<Dual>,
+}
+
+impl FieldGradient {
+    // Se deriva la suma sin tope: por encima de 1 el tope anularía todo el gradiente
+    fn derivative(&self) -> f64 {
+        self.breakdown.uncapped_fitness.derivative
+    }
+
+    // Cuánto se mueve el campo al recorrer todo su prior: dp/du
+    fn unit_derivative(&self) -> f64 {
+        let span = match self.parameter.scale {
+            ParameterScale::Linear => self.parameter.max - self.parameter.min,
+            ParameterScale::Logarithmic => self.value * (self.parameter.max / self.parameter.min).ln(),
+        };
+        self.derivative() * span
+    }
+}
+
+// ∂f/∂p en modo directo: el campo elegido lleva ε y el resto son constantes
+pub fn fitness_gradient(laws: &CosmicLaw, name: &str) -> Option<FitnessBreakdown<Dual>> {
+    let value = laws.parameter(name)?;
+    let mut lifted = laws.lift::<Dual>();
+    *lifted.parameter_mut(name)? = Dual::variable(value);
+    Some(calculate_fitness_breakdown(&lifted))
+}
+
+pub fn run_gradient_mode(universe_path: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let laws = load_universe(universe_path)?;
+    let breakdown = calculate_fitness_breakdown(&laws);
+
+    println!("∂ GRADIENTE EXACTO DEL FITNESS (números duales)");
+    println!(
+        "🌌 Fitness {:.6}, nivel {} ({})",
+        breakdown.fitness,
+        breakdown.complexity_level,
+        analyze_universe_type(breakdown.complexity_level)
+    );
+    if let Some(reason) = &breakdown.failure_reason {
+        println!("⚠️ {}", reason);
+    }
+    if breakdown.uncapped_fitness > breakdown.fitness {
+        println!(
+            "🔝 Tope activo: el fitness está saturado en 1 (suma sin tope {:.4}); se deriva la suma sin tope",
+            breakdown.uncapped_fitness
+        );
+    }
+
+    let gradients: Vec<FieldGradient> = LAW_PARAMETERS
+        .iter()
+        .filter_map(|parameter| {
+            let value = laws.parameter(parameter.name)?;
+            let breakdown = fitness_gradient(&laws, parameter.name)?;
+            Some(FieldGradient { parameter, value, breakdown })
+        })
+        .collect();
+
+    println!("\n{:<34} {:>13} {:>13} {:>13} {:>13}", "campo", "valor", "∂f/∂p", "elasticidad", "∂f/∂u prior");
+    for gradient in &gradients {
+        let elasticity = if breakdown.uncapped_fitness > 0.0 {
+            gradient.derivative() * gradient.value / breakdown.uncapped_fitness
+        } else {
+            0.0
+        };
+        println!(
+            "{:<34} {:>13.4e} {:>13.4e} {:>13.4e} {:>13.4e}",
+            gradient.parameter.name,
+            gradient.value,
+            gradient.derivative(),
+            elasticity,
+            gradient.unit_derivative()
+        );
+    }
+
+    if let Some(steepest) = gradients.iter().max_by(|a, b| a.unit_derivative().abs().total_cmp(&b.unit_derivative().abs())) {
+        println!("\n🧭 Dirección más empinada en el prior: {} ({:+.4e} por ancho de prior)", steepest.parameter.name, steepest.unit_derivative());
+    }
+    println!("ℹ️ La tabla periódica cuenta elementos y entra como constante: d_periodic_table solo recoge las puertas que la multiplican");
+    if gradients.iter().all(|gradient| gradient.derivative() == 0.0) {
+        println!("🪨 Gradiente nulo: el universo está en una meseta de los umbrales duros; prueba con --smooth");
+    }
+
+    let mut wtr = csv::Writer::from_path("gradient.csv")?;
+    let mut header = vec!["field".to_string(), "value".to_string(), "d_fitness".to_string(), "d_fitness_unit".to_string()];
+    header.extend(FITNESS_COMPONENTS.iter().map(|component| format!("d_{}", component)));
+    wtr.write_record(header)?;
+    for gradient in &gradients {
+        let mut record = vec![
+            gradient.parameter.name.to_string(),
+            format!("{:e}", gradient.value),
+            format!("{:e}", gradient.derivative()),
+            format!("{:e}", gradient.unit_derivative()),
+        ];
+        record.extend(FITNESS_COMPONENTS.iter().map(|component| {
+            let derivative = gradient.breakdown.component(component).map(|dual| dual.derivative).unwrap_or(0.0);
+            format!("{:e}", derivative)
+        }));
+        wtr.write_record(record)?;
+    }
+    wtr.flush()?;
+
+    println!("💾 Derivadas por componente en 'gradient.csv'");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dual_value_matches_plain_fitness() {
+        let laws = CosmicLaw::reference();
+        let plain = calculate_fitness_breakdown(&laws);
+        let lifted = fitness_gradient(&laws, "alpha_s").unwrap();
+        assert_eq!(lifted.fitness.value, plain.fitness);
+        assert_eq!(lifted.periodic_table.derivative, 0.0);
+        assert!(fitness_gradient(&laws, "no-existe").is_none());
+    }
+
+    #[test]
+    fn saturated_reference_keeps_a_gradient() {
+        let laws = CosmicLaw::reference();
+        let lifted = fitness_gradient(&laws, "alpha_s").unwrap();
+        assert_eq!(lifted.fitness.value, 1.0);
+        assert!(lifted.uncapped_fitness.value > 1.0);
+        assert_eq!(lifted.fitness.derivative, 0.0, "el tope debería anular la derivada del fitness");
+
+        let any_nonzero = LAW_PARAMETERS.iter().any(|parameter| {
+            fitness_gradient(&laws, parameter.name).is_some_and(|breakdown| breakdown.uncapped_fitness.derivative != 0.0)
+        });
+        assert!(any_nonzero, "la suma sin tope debería responder a algún campo");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::core::models::{CosmicNarrative, CosmicLaw};
-use crate::physics::engine::{AdvancedPhysicsEngine, calculate_enhanced_fitness};
+use crate::physics::engine::{AdvancedPhysicsEngine, calculate_enhanced_fitness, selected_fitness_model};
 use crate::physics::constants::*;
 use std::f64::consts::PI;
 use std::error::Error;
@@ -26,40 +26,13 @@ impl LibraryExplorer {
         let random_epsilon0 = EPSILON_0 * rng.gen_range(0.5..2.0);
         let derived_e = (target_alpha * 4.0 * PI * random_epsilon0 * random_hbar * random_c).sqrt();
 
-        CosmicLaw {
-            g: rng.gen_range(6.674e-11..6.674e-10),
-            e: derived_e,
-            alpha_s: rng.gen_range(0.05..2.0),
-            alpha_w: rng.gen_range(1.0e-9..1.0e-4),
-
-            mass_up_quark: rng.gen_range(1.0e-30..6.0e-30),
-            mass_down_quark: rng.gen_range(1.0e-30..1.3e-29),
-            mass_electron: rng.gen_range(1.0e-31..1.0e-30),
-
-            mass_charm_quark: rng.gen_range(1.0e-29..1.0e-27),
-            mass_strange_quark: rng.gen_range(1.0e-29..1.0e-28),
-            mass_muon: rng.gen_range(1.0e-29..1.0e-27),
-
-            mass_top_quark: rng.gen_range(1.0e-28..1.0e-25),
-            mass_bottom_quark: rng.gen_range(1.0e-28..1.0e-27),
-            mass_tauon: rng.gen_range(1.0e-28..1.0e-26),
-
-            spatial_curvature: rng.gen_range(-1.0..1.0),
-            dimensional_ratios: [
-                rng.gen_range(0.5..2.0),
-                rng.gen_range(0.5..2.0),
-                rng.gen_range(0.5..2.0),
-                rng.gen_range(0.5..2.0),
-            ],
-            temporal_evolution_rate: rng.gen_range(0.1..10.0),
-
-            dark_energy_density: rng.gen_range(0.0..2.0e-29),
-            dark_matter_coupling: rng.gen_range(0.0..1.0),
-        }
+        let mut laws = CosmicLaw::sample_prior(&CosmicLaw::reference(), rng);
+        laws.e = derived_e;
+        laws
     }
 
     pub fn analyze_cosmic_narrative(&self, laws: &CosmicLaw) -> CosmicNarrative {
-        let engine = AdvancedPhysicsEngine::new(laws.clone());
+        let engine = AdvancedPhysicsEngine::new(laws.clone()).with_fitness_model(selected_fitness_model());
         let (fitness, complexity_level) = calculate_enhanced_fitness(laws);
 
         let mut dramatic_events = Vec::new();
@@ -69,6 +42,11 @@ impl LibraryExplorer {
             dramatic_events.push("The Great Nucleosynthesis".to_string());
         }
 
+        let vacuum = engine.vacuum_domination();
+        if vacuum.nonlinear_scale_factor < vacuum.vacuum_scale_factor.min(vacuum.curvature_scale_factor) {
+            dramatic_events.push("The Cosmic Web Forms".to_string());
+        }
+
         if engine.stellar_formation_epoch() > 0.5 {
             dramatic_events.push("The Age of Stars Begins".to_string());
         }
@@ -81,6 +59,10 @@ impl LibraryExplorer {
             dramatic_events.push("The Portal Makers Awaken".to_string());
         }
 
+        if engine.habitable_planet_score() > 0.5 {
+            dramatic_events.push("The Oceans Settle".to_string());
+        }
+
         story_quality *= 1.0 + dramatic_events.len() as f64 * 0.2;
 
         let finale_type = match complexity_level {
@@ -90,6 +72,7 @@ impl LibraryExplorer {
             3 => "Stellar Symphony".to_string(),
             4 => "Complex Choreography".to_string(),
             5 => "Reproductive Renaissance".to_string(),
+            6 => "Living Worlds".to_string(),
             _ => "Unknown Epic".to_string(),
         };
 
@@ -138,7 +121,8 @@ pub fn run_library_mode(
      wtr.write_record(&[
         "alpha_denominator", "story_quality", "narrative_depth", "finale_type", "reproducibility", "dramatic_events_count",
         "theoretical_proton_mass_kg", "theoretical_mass_diff_mev",
-        "jeans_mass_kg", "stellar_lifetime_years", "stellar_score"
+        "jeans_mass_kg", "stellar_lifetime_years", "stellar_score",
+        "galaxy_mass_kg", "galaxy_radius_m", "cooling_temperature_k", "cloud_temperature_k"
     ])?;
 
     println!("📚 Explorando la Biblioteca Cósmica...");
@@ -163,6 +147,7 @@ for alpha_denom in min_alpha..=max_alpha {
         };
         
         let mut best_diag_data = (0.0, 0.0, 0.0, 0.0, 0.0);
+        let mut best_galaxy = None;
 
         for _ in 0..samples_per_book {
             let universe = explorer.generate_universe_at_alpha(target_alpha, &mut rng);
@@ -171,7 +156,7 @@ for alpha_denom in min_alpha..=max_alpha {
             if narrative.story_quality > best_narrative.story_quality {
                 best_narrative = narrative;
                 
-                let engine = AdvancedPhysicsEngine::new(universe.clone());
+                let engine = AdvancedPhysicsEngine::new(universe.clone()).with_fitness_model(selected_fitness_model());
                 let (p_mass, n_mass, _) = engine.get_theoretical_hadron_masses();
                 let mass_diff_mev = (n_mass - p_mass) / MEV_TO_KG;
                 let jeans_mass = engine.calculate_jeans_mass();
@@ -180,6 +165,7 @@ for alpha_denom in min_alpha..=max_alpha {
                 let stellar_score = engine.stellar_formation_epoch();
                 
                 best_diag_data = (p_mass, mass_diff_mev, jeans_mass, lifetime_years, stellar_score);
+                best_galaxy = Some(engine.galaxy_formation());
             }
         }
         
@@ -197,6 +183,10 @@ for alpha_denom in min_alpha..=max_alpha {
             format!("{:e}", best_diag_data.2), // jeans_mass_kg
             format!("{:e}", best_diag_data.3), // stellar_lifetime_years
             format!("{:.6}", best_diag_data.4),  // stellar_score
+            format!("{:e}", best_galaxy.map_or(0.0, |g| g.galaxy_mass)),
+            format!("{:e}", best_galaxy.map_or(0.0, |g| g.galaxy_radius)),
+            format!("{:e}", best_galaxy.map_or(0.0, |g| g.cooling_temperature)),
+            format!("{:e}", best_galaxy.map_or(0.0, |g| g.cloud_temperature)),
         ])?;
 
         if best_narrative.story_quality > 0.8 {
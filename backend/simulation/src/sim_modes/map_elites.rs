@@ -0,0 +1,251 @@
+use crate::core::models::{CosmicLaw, COMPLEXITY_LEVELS};
+use crate::physics::constants::*;
+use crate::physics::engine::calculate_enhanced_fitness;
+use crate::utils::{analyze_universe_type, curvature_class};
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::f64::consts::PI;
+use std::path::Path;
+use csv;
+
+// Mismo rango de la Biblioteca Cósmica, en bins logarítmicos de 1/α.
+// El prior de e solo cubre 1/α ≈ 56–1400: la siembra fija 1/α en cada bin y deriva e
+const MIN_INVERSE_ALPHA: f64 = 1.0;
+const MAX_INVERSE_ALPHA: f64 = 1000.0;
+const CROSSOVER_PROBABILITY: f64 = 0.2;
+const CHECKPOINT_INTERVAL: u32 = 1000;
+const CURVATURE_CLASSES: [&str; 3] = ["Hyperbolic", "Flat", "Spherical"];
+
+type Niche = (usize, u8, usize); // (bin de 1/α, nivel de complejidad, clase de curvatura)
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Elite {
+    inverse_alpha_bin: usize,
+    complexity_level: u8,
+    curvature_class: String,
+    fitness: f64,
+    laws: CosmicLaw,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Archive {
+    inverse_alpha_bins: usize,
+    iterations: u64,
+    elites: Vec<Elite>,
+}
+
+fn inverse_alpha(laws: &CosmicLaw) -> f64 {
+    4.0 * PI * EPSILON_0 * H_BAR * C / laws.e.powi(2)
+}
+
+fn inverse_alpha_bin(value: f64, bins: usize) -> Option<usize> {
+    if !(MIN_INVERSE_ALPHA..MAX_INVERSE_ALPHA).contains(&value) {
+        return None;
+    }
+    let position = (value / MIN_INVERSE_ALPHA).ln() / (MAX_INVERSE_ALPHA / MIN_INVERSE_ALPHA).ln();
+    Some(((position * bins as f64) as usize).min(bins - 1))
+}
+
+fn bin_edges(bin: usize, bins: usize) -> (f64, f64) {
+    let ratio = MAX_INVERSE_ALPHA / MIN_INVERSE_ALPHA;
+    let edge = |index: usize| MIN_INVERSE_ALPHA * ratio.powf(index as f64 / bins as f64);
+    (edge(bin), edge(bin + 1))
+}
+
+// e² = 4πε₀ħc α
+fn with_inverse_alpha(mut laws: CosmicLaw, value: f64) -> CosmicLaw {
+    laws.e = (4.0 * PI * EPSILON_0 * H_BAR * C / value).sqrt();
+    laws
+}
+
+fn evaluate(laws: CosmicLaw, bins: usize) -> Option<(Niche, Elite)> {
+    let alpha_bin = inverse_alpha_bin(inverse_alpha(&laws), bins)?;
+    let (fitness, complexity_level) = calculate_enhanced_fitness(&laws);
+    let class = curvature_class(laws.spatial_curvature);
+    let class_index = CURVATURE_CLASSES.iter().position(|c| *c == class).unwrap_or(1);
+
+    Some((
+        (alpha_bin, complexity_level, class_index),
+        Elite {
+            inverse_alpha_bin: alpha_bin,
+            complexity_level,
+            curvature_class: class.to_string(),
+            fitness,
+            laws,
+        },
+    ))
+}
+
+// Un candidato ocupa su nicho si está vacío o si supera al élite actual
+fn try_insert(archive: &mut BTreeMap<Niche, Elite>, laws: CosmicLaw, bins: usize) -> bool {
+    let Some((niche, candidate)) = evaluate(laws, bins) else {
+        return false;
+    };
+    match archive.get(&niche) {
+        Some(elite) if elite.fitness >= candidate.fitness => false,
+        _ => {
+            archive.insert(niche, candidate);
+            true
+        }
+    }
+}
+
+fn save_archive(path: &str, archive: &BTreeMap<Niche, Elite>, bins: usize, iterations: u64) -> Result<(), Box<dyn Error>> {
+    let snapshot = Archive {
+        inverse_alpha_bins: bins,
+        iterations,
+        elites: archive.values().cloned().collect(),
+    };
+    std::fs::write(path, serde_json::to_string_pretty(&snapshot)?)?;
+    Ok(())
+}
+
+fn write_grid(archive: &BTreeMap<Niche, Elite>, bins: usize) -> Result<(), Box<dyn Error>> {
+    let mut wtr = csv::Writer::from_path("map_elites_grid.csv")?;
+    wtr.write_record([
+        "inverse_alpha_bin",
+        "inverse_alpha_min",
+        "inverse_alpha_max",
+        "complexity_level",
+        "type",
+        "curvature_class",
+        "fitness",
+        "inverse_alpha",
+    ])?;
+    for elite in archive.values() {
+        let (low, high) = bin_edges(elite.inverse_alpha_bin, bins);
+        wtr.write_record([
+            elite.inverse_alpha_bin.to_string(),
+            format!("{:.3}", low),
+            format!("{:.3}", high),
+            elite.complexity_level.to_string(),
+            analyze_universe_type(elite.complexity_level).to_string(),
+            elite.curvature_class.clone(),
+            format!("{:.6}", elite.fitness),
+            format!("{:.4}", inverse_alpha(&elite.laws)),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+// MAP-Elites: el mejor universo de cada región (1/α, complejidad, curvatura) en lugar de un único campeón
+pub fn run_map_elites_mode(
+    iterations: u32,
+    initial_samples: u32,
+    mutation_strength: f64,
+    bins: usize,
+    archive_path: &str,
+    resume: bool,
+) -> Result<(), Box<dyn Error>> {
+    let mut rng = thread_rng();
+    let reference = CosmicLaw::reference();
+    let mut archive: BTreeMap<Niche, Elite> = BTreeMap::new();
+    let mut completed_iterations: u64 = 0;
+    let mut bins = bins.max(1);
+
+    println!("🗂️ MAP-ELITES: archivo de calidad-diversidad");
+
+    if resume && Path::new(archive_path).exists() {
+        let saved: Archive = serde_json::from_str(&std::fs::read_to_string(archive_path)?)?;
+        if saved.inverse_alpha_bins != bins {
+            println!(
+                "⚠️ --bins {} ignorado: '{}' se creó con {} bins de 1/α",
+                bins, archive_path, saved.inverse_alpha_bins
+            );
+        }
+        bins = saved.inverse_alpha_bins;
+        completed_iterations = saved.iterations;
+        for elite in saved.elites {
+            let class_index = CURVATURE_CLASSES.iter().position(|c| *c == elite.curvature_class).unwrap_or(1);
+            archive.insert((elite.inverse_alpha_bin, elite.complexity_level, class_index), elite);
+        }
+        println!(
+            "♻️ Reanudando '{}': {} élites tras {} iteraciones",
+            archive_path,
+            archive.len(),
+            completed_iterations
+        );
+    } else {
+        println!("🌱 Sembrando el archivo con {} universos del prior, repartidos por los bins de 1/α", initial_samples);
+        for sample in 0..initial_samples {
+            let (low, high) = bin_edges(sample as usize % bins, bins);
+            let value = low * (high / low).powf(rng.gen::<f64>());
+            try_insert(&mut archive, with_inverse_alpha(CosmicLaw::sample_prior(&reference, &mut rng), value), bins);
+        }
+    }
+
+    if archive.is_empty() {
+        println!("💀 Ningún universo cayó dentro del rango de 1/α; no hay nada que mutar");
+        return Ok(());
+    }
+
+    println!("📏 {} bins de 1/α × {} niveles × {} curvaturas", bins, COMPLEXITY_LEVELS, CURVATURE_CLASSES.len());
+
+    for iteration in 1..=iterations {
+        let elites: Vec<&Elite> = archive.values().collect();
+        let parent = elites[rng.gen_range(0..elites.len())];
+        let mut child = parent.laws.clone();
+        if rng.gen_bool(CROSSOVER_PROBABILITY) {
+            let partner = elites[rng.gen_range(0..elites.len())];
+            child = child.crossover(&partner.laws, &mut rng);
+        }
+        try_insert(&mut archive, child.mutated(mutation_strength, &mut rng), bins);
+
+        if iteration % CHECKPOINT_INTERVAL == 0 {
+            save_archive(archive_path, &archive, bins, completed_iterations + iteration as u64)?;
+            let best = archive.values().map(|elite| elite.fitness).fold(0.0, f64::max);
+            println!("🔁 Iteración {}: {} nichos ocupados | mejor fitness {:.4}", iteration, archive.len(), best);
+        }
+    }
+
+    completed_iterations += iterations as u64;
+    save_archive(archive_path, &archive, bins, completed_iterations)?;
+    write_grid(&archive, bins)?;
+
+    println!("\n📊 === ARCHIVO MAP-ELITES ({} iteraciones) ===", completed_iterations);
+    println!("   Nichos ocupados: {} de {}", archive.len(), bins * COMPLEXITY_LEVELS * CURVATURE_CLASSES.len());
+    let mut levels: BTreeMap<u8, usize> = BTreeMap::new();
+    for elite in archive.values() {
+        *levels.entry(elite.complexity_level).or_insert(0) += 1;
+    }
+    for (level, count) in levels {
+        println!("   Nivel {} ({}): {} nichos", level, analyze_universe_type(level), count);
+    }
+
+    if let Some(bin) = inverse_alpha_bin(1.0 / ALPHA_EMPIRICAL, bins) {
+        let (low, high) = bin_edges(bin, bins);
+        let ours = archive.values().filter(|elite| elite.inverse_alpha_bin == bin).map(|elite| elite.fitness).fold(0.0, f64::max);
+        println!("   Mejor universo con 1/α en [{:.1}, {:.1}): fitness {:.4}", low, high, ours);
+    }
+
+    println!("💾 Archivo en '{}', rejilla en 'map_elites_grid.csv'", archive_path);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_inverse_alpha_lands_in_its_bin() {
+        let bins = 30;
+        for bin in 0..bins {
+            let (low, high) = bin_edges(bin, bins);
+            let laws = with_inverse_alpha(CosmicLaw::reference(), (low * high).sqrt());
+            assert_eq!(inverse_alpha_bin(inverse_alpha(&laws), bins), Some(bin));
+        }
+        assert_eq!(inverse_alpha_bin(MAX_INVERSE_ALPHA, bins), None);
+    }
+
+    #[test]
+    fn elite_is_only_replaced_by_a_strictly_fitter_universe() {
+        let mut archive = BTreeMap::new();
+        assert!(try_insert(&mut archive, CosmicLaw::reference(), 30));
+        assert!(!try_insert(&mut archive, CosmicLaw::reference(), 30));
+        assert_eq!(archive.len(), 1);
+    }
+}
@@ -1,9 +1,18 @@
 pub mod alpha_fixed;
 pub mod evolve;
 pub mod geometric;
+pub mod gradient;
 pub mod harmonic;
 pub mod library;
 pub mod map;
+pub mod map_elites;
+pub mod natural_selection;
+pub mod optimize;
+pub mod pareto;
+pub mod sensitivity;
+pub mod sobol;
+pub mod evaluate;
 pub mod validate;
+pub mod vev_scan;
 pub mod stream;
 pub mod simulation_engine;
@@ -0,0 +1,252 @@
+use crate::core::models::CosmicLaw;
+use crate::core::parameters::LAW_PARAMETERS;
+use crate::physics::constants::*;
+use crate::physics::engine::{AdvancedPhysicsEngine, calculate_fitness_breakdown, selected_fitness_model};
+use crate::sim_modes::validate::load_universe;
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+use std::collections::HashMap;
+use std::error::Error;
+use std::f64::consts::PI;
+use csv;
+
+const CONVERGENCE_WINDOW_FRACTION: f64 = 0.1; // Primeras y últimas generaciones que se comparan
+const LOCAL_MAXIMUM_PROBES: usize = 200; // Mutantes del universo de referencia para la prueba de Smolin
+
+#[derive(Debug, Clone)]
+struct Individual {
+    laws: CosmicLaw,
+    lineage: usize,
+    black_holes: f64,
+    fitness: f64,
+}
+
+// Distancia RMS en anchos de prior: 0 es nuestro universo, ~0.4 es un universo al azar
+fn distance_to(laws: &CosmicLaw, reference: &CosmicLaw) -> f64 {
+    let squared: f64 = LAW_PARAMETERS
+        .iter()
+        .filter_map(|parameter| {
+            let value = laws.parameter(parameter.name)?;
+            let target = reference.parameter(parameter.name)?;
+            Some((parameter.unit_position(value) - parameter.unit_position(target)).powi(2))
+        })
+        .sum();
+    (squared / LAW_PARAMETERS.len() as f64).sqrt()
+}
+
+fn inverse_alpha(laws: &CosmicLaw) -> f64 {
+    4.0 * PI * EPSILON_0 * H_BAR * C / laws.e.powi(2)
+}
+
+// Agujeros negros por universo: colapso estelar (reproducibility) en las estructuras que llegan a formarse
+fn black_hole_production(laws: &CosmicLaw) -> (f64, f64) {
+    let breakdown = calculate_fitness_breakdown(laws);
+    if breakdown.failure_reason.is_some() {
+        return (0.0, breakdown.fitness);
+    }
+    let engine = AdvancedPhysicsEngine::new(laws.clone()).with_fitness_model(selected_fitness_model());
+    let production = engine.black_hole_formation_potential() * breakdown.structure * breakdown.cosmology;
+    (production, breakdown.fitness)
+}
+
+fn evaluate(laws: CosmicLaw, lineage: usize) -> Individual {
+    let (black_holes, fitness) = black_hole_production(&laws);
+    Individual {
+        laws,
+        lineage,
+        black_holes,
+        fitness,
+    }
+}
+
+// Smolin: cada universo engendra tantos hijos como agujeros negros produce, con leyes ligeramente mutadas
+pub fn run_natural_selection_mode(
+    population_size: usize,
+    generations: u32,
+    mutation_strength: f64,
+    seed_path: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let mut rng = thread_rng();
+    let reference = CosmicLaw::reference();
+
+    println!("🌱 SELECCIÓN NATURAL COSMOLÓGICA (Smolin)");
+    println!(
+        "👥 Población: {} | Generaciones: {} | Mutación: {:.3} anchos de prior",
+        population_size, generations, mutation_strength
+    );
+
+    // Sin semilla, los fundadores salen del prior; con semilla, son mutantes de ella
+    let founders: Vec<CosmicLaw> = match seed_path {
+        Some(path) => {
+            let seed = load_universe(Some(path))?;
+            (0..population_size).map(|_| seed.mutated(mutation_strength, &mut rng)).collect()
+        }
+        None => (0..population_size).map(|_| CosmicLaw::sample_prior(&reference, &mut rng)).collect(),
+    };
+    let mut population: Vec<Individual> = founders
+        .into_iter()
+        .enumerate()
+        .map(|(lineage, laws)| evaluate(laws, lineage))
+        .collect();
+
+    let mut wtr = csv::Writer::from_path("natural_selection.csv")?;
+    wtr.write_record([
+        "generation",
+        "mean_black_holes",
+        "max_black_holes",
+        "fertile_fraction",
+        "mean_fitness",
+        "mean_inverse_alpha",
+        "surviving_lineages",
+        "dominant_lineage_share",
+        "distance_to_reference",
+    ])?;
+
+    let mut distances = Vec::with_capacity(generations as usize);
+    let mut champion = population[0].clone();
+
+    for generation in 0..generations {
+        let size = population.len() as f64;
+        let mean_black_holes = population.iter().map(|i| i.black_holes).sum::<f64>() / size;
+        let max_black_holes = population.iter().map(|i| i.black_holes).fold(0.0, f64::max);
+        let fertile_fraction = population.iter().filter(|i| i.black_holes > 0.0).count() as f64 / size;
+        let mean_fitness = population.iter().map(|i| i.fitness).sum::<f64>() / size;
+        let mean_inverse_alpha = population.iter().map(|i| inverse_alpha(&i.laws)).sum::<f64>() / size;
+        let distance = population.iter().map(|i| distance_to(&i.laws, &reference)).sum::<f64>() / size;
+
+        let mut lineage_sizes: HashMap<usize, usize> = HashMap::new();
+        for individual in &population {
+            *lineage_sizes.entry(individual.lineage).or_insert(0) += 1;
+        }
+        let dominant_share = lineage_sizes.values().copied().max().unwrap_or(0) as f64 / size;
+
+        for individual in &population {
+            if individual.black_holes > champion.black_holes {
+                champion = individual.clone();
+            }
+        }
+
+        wtr.write_record(&[
+            generation.to_string(),
+            format!("{:.6}", mean_black_holes),
+            format!("{:.6}", max_black_holes),
+            format!("{:.4}", fertile_fraction),
+            format!("{:.6}", mean_fitness),
+            format!("{:.4}", mean_inverse_alpha),
+            lineage_sizes.len().to_string(),
+            format!("{:.4}", dominant_share),
+            format!("{:.6}", distance),
+        ])?;
+        distances.push(distance);
+
+        if generation % 10 == 0 {
+            println!(
+                "🧬 Generación {}: ⟨BH⟩ = {:.4} | fértiles {:.1}% | linajes {} | distancia {:.4}",
+                generation,
+                mean_black_holes,
+                100.0 * fertile_fraction,
+                lineage_sizes.len(),
+                distance
+            );
+        }
+
+        // Sin agujeros negros en ninguna parte la población solo deriva
+        let weights: Vec<f64> = population.iter().map(|i| i.black_holes).collect();
+        let parents = if weights.iter().sum::<f64>() > 0.0 {
+            let distribution = WeightedIndex::new(&weights)?;
+            (0..population.len()).map(|_| distribution.sample(&mut rng)).collect::<Vec<_>>()
+        } else {
+            (0..population.len()).map(|_| rng.gen_range(0..population.len())).collect()
+        };
+
+        population = parents
+            .into_iter()
+            .map(|parent| {
+                let offspring = population[parent].laws.mutated(mutation_strength, &mut rng);
+                evaluate(offspring, population[parent].lineage)
+            })
+            .collect();
+    }
+
+    wtr.flush()?;
+
+    let mut lineage_sizes: HashMap<usize, Vec<&Individual>> = HashMap::new();
+    for individual in &population {
+        lineage_sizes.entry(individual.lineage).or_default().push(individual);
+    }
+    let mut lineages: Vec<_> = lineage_sizes.into_iter().collect();
+    lineages.sort_by_key(|(_, members)| std::cmp::Reverse(members.len()));
+
+    println!("\n🌳 === LINAJES SUPERVIVIENTES: {} de {} ===", lineages.len(), population_size);
+    for (lineage, members) in lineages.iter().take(5) {
+        let mean_black_holes = members.iter().map(|i| i.black_holes).sum::<f64>() / members.len() as f64;
+        let mean_inverse_alpha = members.iter().map(|i| inverse_alpha(&i.laws)).sum::<f64>() / members.len() as f64;
+        println!(
+            "   Linaje #{}: {} universos | ⟨BH⟩ = {:.4} | ⟨1/α⟩ = {:.2}",
+            lineage,
+            members.len(),
+            mean_black_holes,
+            mean_inverse_alpha
+        );
+    }
+
+    // Convergencia: ¿se acerca la población a nuestro universo?
+    let window = ((distances.len() as f64 * CONVERGENCE_WINDOW_FRACTION).ceil() as usize).max(1);
+    if distances.len() >= 2 * window {
+        let early = distances[..window].iter().sum::<f64>() / window as f64;
+        let late = distances[distances.len() - window..].iter().sum::<f64>() / window as f64;
+        println!("\n🎯 === PRUEBA DE CONVERGENCIA ===");
+        println!("   Distancia a nuestro universo: {:.4} → {:.4}", early, late);
+        if late < early {
+            println!("   ✅ La población converge hacia nuestro universo ({:.1}% más cerca)", 100.0 * (1.0 - late / early));
+        } else {
+            println!("   ❌ La población no converge hacia nuestro universo");
+        }
+    }
+
+    // Predicción de Smolin: nuestro universo debería ser un máximo local de producción de agujeros negros
+    let (reference_black_holes, _) = black_hole_production(&reference);
+    let less_fertile = (0..LOCAL_MAXIMUM_PROBES)
+        .filter(|_| {
+            let mutant = reference.mutated(mutation_strength, &mut rng);
+            black_hole_production(&mutant).0 <= reference_black_holes
+        })
+        .count();
+    println!(
+        "   Nuestro universo: BH = {:.4}; {:.1}% de sus mutantes producen menos o igual",
+        reference_black_holes,
+        100.0 * less_fertile as f64 / LOCAL_MAXIMUM_PROBES as f64
+    );
+
+    std::fs::write("natural_selection_champion.json", serde_json::to_string_pretty(&champion.laws)?)?;
+    println!("\n🏆 Campeón reproductivo: BH = {:.4}, fitness = {:.4}", champion.black_holes, champion.fitness);
+    println!("💾 Historia guardada en 'natural_selection.csv', campeón en 'natural_selection_champion.json'");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn prior_universes_sit_far_from_ours() {
+        let reference = CosmicLaw::reference();
+        let mut rng = StdRng::seed_from_u64(7);
+        assert_eq!(distance_to(&reference, &reference), 0.0);
+        let mean = (0..200).map(|_| distance_to(&CosmicLaw::sample_prior(&reference, &mut rng), &reference)).sum::<f64>() / 200.0;
+        assert!((0.25..0.5).contains(&mean), "distancia media {}", mean);
+    }
+
+    #[test]
+    fn only_viable_universes_make_black_holes() {
+        let (black_holes, fitness) = black_hole_production(&CosmicLaw::reference());
+        assert!(black_holes > 0.0);
+        assert!(fitness > 0.0);
+
+        let mut barren = CosmicLaw::reference();
+        barren.e = 0.0;
+        assert_eq!(black_hole_production(&barren).0, 0.0);
+    }
+}
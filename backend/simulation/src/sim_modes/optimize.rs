@@ -0,0 +1,418 @@
+use crate::core::models::CosmicLaw;
+use crate::core::parameters::{law_parameter, LawParameter, LAW_PARAMETERS};
+use crate::physics::engine::calculate_enhanced_fitness;
+use crate::sim_modes::validate::load_universe;
+use crate::utils::analyze_universe_type;
+use rand::prelude::*;
+use rand_distr::StandardNormal;
+use std::error::Error;
+use csv;
+
+const MIN_STEP_SIZE: f64 = 1e-8; // En anchos de prior: por debajo, CMA-ES ya no se mueve
+const INITIAL_TEMPERATURE: f64 = 0.05; // En unidades de fitness
+const FINAL_TEMPERATURE: f64 = 1e-4;
+
+// α = e² / (4π ε₀ ħ c): fijar α es fijar la carga elemental
+fn resolve_field(name: &str) -> &str {
+    match name {
+        "alpha" => "e",
+        other => other,
+    }
+}
+
+fn parse_fields(list: Option<&str>) -> Result<Vec<&str>, Box<dyn Error>> {
+    let Some(list) = list else {
+        return Ok(Vec::new());
+    };
+    list.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            let field = resolve_field(name);
+            law_parameter(field).map(|parameter| parameter.name).ok_or_else(|| {
+                let available: Vec<&str> = LAW_PARAMETERS.iter().map(|parameter| parameter.name).collect();
+                format!("Campo desconocido '{}'; disponibles: alpha, {}", name, available.join(", ")).into()
+            })
+        })
+        .collect()
+}
+
+struct Problem<'a> {
+    start: CosmicLaw,
+    parameters: Vec<&'a LawParameter>,
+    objective: fn(&CosmicLaw) -> (f64, u8),
+    evaluations: u64,
+}
+
+impl Problem<'_> {
+    fn laws_at(&self, point: &[f64]) -> CosmicLaw {
+        let mut laws = self.start.clone();
+        for (parameter, &unit) in self.parameters.iter().zip(point) {
+            laws.set_unit_position(parameter, unit);
+        }
+        laws
+    }
+
+    fn fitness(&mut self, point: &[f64]) -> (f64, u8) {
+        self.evaluations += 1;
+        (self.objective)(&self.laws_at(point))
+    }
+}
+
+// C = L Lᵀ; si el redondeo la deja indefinida se añade un poco de diagonal
+fn cholesky(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = matrix.len();
+    let mut jitter = 0.0;
+    loop {
+        let mut lower = vec![vec![0.0; n]; n];
+        let mut positive = true;
+        'rows: for i in 0..n {
+            for j in 0..=i {
+                let partial: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+                if i == j {
+                    let diagonal = matrix[i][i] + jitter - partial;
+                    if diagonal <= 0.0 {
+                        positive = false;
+                        break 'rows;
+                    }
+                    lower[i][i] = diagonal.sqrt();
+                } else {
+                    lower[i][j] = (matrix[i][j] - partial) / lower[j][j];
+                }
+            }
+        }
+        if positive {
+            return lower;
+        }
+        jitter = if jitter == 0.0 { 1e-12 } else { jitter * 10.0 };
+    }
+}
+
+// Una muestra de CMA-ES: z ~ N(0, I), y = L z y su fitness
+struct Sample {
+    z: Vec<f64>,
+    y: Vec<f64>,
+    fitness: f64,
+}
+
+fn weighted_mean(weights: &[f64], samples: &[Sample], select: impl Fn(&Sample) -> &[f64]) -> Vec<f64> {
+    let n = select(&samples[0]).len();
+    (0..n).map(|i| weights.iter().zip(samples).map(|(w, sample)| w * select(sample)[i]).sum()).collect()
+}
+
+struct Progress {
+    iteration: u32,
+    best_fitness: f64,
+    best_level: u8,
+    current_fitness: f64,
+    step_size: f64,
+}
+
+// CMA-ES (μ/μ_w, λ) de Hansen en el espacio unitario de los priors (logarítmico para masas y acoplamientos)
+fn run_cma_es(
+    problem: &mut Problem,
+    iterations: u32,
+    initial_step: f64,
+    rng: &mut impl Rng,
+    log: &mut dyn FnMut(Progress) -> Result<(), Box<dyn Error>>,
+) -> Result<(Vec<f64>, f64, u8), Box<dyn Error>> {
+    let n = problem.parameters.len();
+    let dimension = n as f64;
+    let lambda = 4 + (3.0 * dimension.ln()).floor() as usize;
+    let mu = lambda / 2;
+    let raw_weights: Vec<f64> = (1..=mu).map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln()).collect();
+    let weight_sum: f64 = raw_weights.iter().sum();
+    let weights: Vec<f64> = raw_weights.iter().map(|w| w / weight_sum).collect();
+    let mu_eff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+    let c_sigma = (mu_eff + 2.0) / (dimension + mu_eff + 5.0);
+    let d_sigma = 1.0 + 2.0 * (((mu_eff - 1.0) / (dimension + 1.0)).sqrt() - 1.0).max(0.0) + c_sigma;
+    let c_c = (4.0 + mu_eff / dimension) / (dimension + 4.0 + 2.0 * mu_eff / dimension);
+    let c_1 = 2.0 / ((dimension + 1.3).powi(2) + mu_eff);
+    let c_mu = (1.0 - c_1).min(2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / ((dimension + 2.0).powi(2) + mu_eff));
+    let expected_norm = dimension.sqrt() * (1.0 - 1.0 / (4.0 * dimension) + 1.0 / (21.0 * dimension * dimension));
+
+    let mut mean: Vec<f64> = problem
+        .parameters
+        .iter()
+        .map(|parameter| parameter.unit_position(problem.start.parameter(parameter.name).unwrap_or(0.0)))
+        .collect();
+    let mut sigma = initial_step;
+    let mut covariance: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+    let mut path_sigma = vec![0.0; n];
+    let mut path_c = vec![0.0; n];
+
+    let (mut best_fitness, mut best_level) = problem.fitness(&mean);
+    let mut best_point = mean.clone();
+
+    for iteration in 0..iterations {
+        let lower = cholesky(&covariance);
+
+        // Muestras x = m + σ L z con z ~ N(0, I)
+        let mut offspring: Vec<Sample> = (0..lambda)
+            .map(|_| {
+                let z: Vec<f64> = (0..n).map(|_| rng.sample(StandardNormal)).collect();
+                let y: Vec<f64> = (0..n).map(|i| (0..=i).map(|k| lower[i][k] * z[k]).sum()).collect();
+                let point: Vec<f64> = mean.iter().zip(&y).map(|(m, step)| m + sigma * step).collect();
+                let (fitness, level) = problem.fitness(&point);
+                if fitness > best_fitness {
+                    best_fitness = fitness;
+                    best_level = level;
+                    best_point = point;
+                }
+                Sample { z, y, fitness }
+            })
+            .collect();
+        offspring.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
+
+        let z_mean = weighted_mean(&weights, &offspring, |sample| &sample.z);
+        let y_mean = weighted_mean(&weights, &offspring, |sample| &sample.y);
+        for i in 0..n {
+            mean[i] += sigma * y_mean[i];
+        }
+
+        // Con L fija durante la generación, L⁻¹ ⟨y⟩_w = ⟨z⟩_w
+        let sigma_gain = (c_sigma * (2.0 - c_sigma) * mu_eff).sqrt();
+        for i in 0..n {
+            path_sigma[i] = (1.0 - c_sigma) * path_sigma[i] + sigma_gain * z_mean[i];
+        }
+        let path_sigma_norm = path_sigma.iter().map(|p| p * p).sum::<f64>().sqrt();
+        let correction = (1.0 - (1.0 - c_sigma).powi(2 * (iteration as i32 + 1))).sqrt();
+        let path_in_bounds = path_sigma_norm / correction < (1.4 + 2.0 / (dimension + 1.0)) * expected_norm;
+        let h_sigma = if path_in_bounds { 1.0 } else { 0.0 };
+
+        let c_gain = (c_c * (2.0 - c_c) * mu_eff).sqrt();
+        for i in 0..n {
+            path_c[i] = (1.0 - c_c) * path_c[i] + h_sigma * c_gain * y_mean[i];
+        }
+
+        let lost_variance = (1.0 - h_sigma) * c_c * (2.0 - c_c);
+        for i in 0..n {
+            for j in 0..n {
+                let rank_mu: f64 = weights.iter().zip(&offspring).map(|(w, sample)| w * sample.y[i] * sample.y[j]).sum();
+                covariance[i][j] = (1.0 - c_1 - c_mu) * covariance[i][j]
+                    + c_1 * (path_c[i] * path_c[j] + lost_variance * covariance[i][j])
+                    + c_mu * rank_mu;
+            }
+        }
+
+        sigma *= ((c_sigma / d_sigma) * (path_sigma_norm / expected_norm - 1.0)).exp();
+
+        log(Progress {
+            iteration,
+            best_fitness,
+            best_level,
+            current_fitness: offspring[0].fitness,
+            step_size: sigma,
+        })?;
+
+        if sigma < MIN_STEP_SIZE {
+            println!("🛑 σ = {:.2e}: la distribución ha colapsado", sigma);
+            break;
+        }
+    }
+
+    Ok((best_point, best_fitness, best_level))
+}
+
+// Recocido simulado: enfriamiento geométrico con paso gaussiano que se estrecha con la temperatura
+fn run_annealing(
+    problem: &mut Problem,
+    iterations: u32,
+    initial_step: f64,
+    rng: &mut impl Rng,
+    log: &mut dyn FnMut(Progress) -> Result<(), Box<dyn Error>>,
+) -> Result<(Vec<f64>, f64, u8), Box<dyn Error>> {
+    let mut current: Vec<f64> = problem
+        .parameters
+        .iter()
+        .map(|parameter| parameter.unit_position(problem.start.parameter(parameter.name).unwrap_or(0.0)))
+        .collect();
+    let (mut current_fitness, mut best_level) = problem.fitness(&current);
+    let mut best_fitness = current_fitness;
+    let mut best_point = current.clone();
+    let cooling = FINAL_TEMPERATURE / INITIAL_TEMPERATURE;
+
+    for iteration in 0..iterations {
+        let progress = iteration as f64 / iterations.max(1) as f64;
+        let temperature = INITIAL_TEMPERATURE * cooling.powf(progress);
+        let step = initial_step * (temperature / INITIAL_TEMPERATURE).sqrt();
+
+        let candidate: Vec<f64> = current
+            .iter()
+            .map(|unit| unit + step * rng.sample::<f64, _>(StandardNormal))
+            .collect();
+        let (fitness, level) = problem.fitness(&candidate);
+
+        if fitness >= current_fitness || rng.gen::<f64>() < ((fitness - current_fitness) / temperature).exp() {
+            current = candidate;
+            current_fitness = fitness;
+        }
+        if fitness > best_fitness {
+            best_fitness = fitness;
+            best_level = level;
+            best_point = current.clone();
+        }
+
+        log(Progress {
+            iteration,
+            best_fitness,
+            best_level,
+            current_fitness,
+            step_size: step,
+        })?;
+    }
+
+    Ok((best_point, best_fitness, best_level))
+}
+
+// Refinamiento local de un campeón sobre un subconjunto de campos, con el resto bloqueado
+pub fn run_optimize_mode(
+    seed_path: Option<&str>,
+    algorithm: &str,
+    fields: Option<&str>,
+    locked: Option<&str>,
+    iterations: u32,
+    step_size: f64,
+) -> Result<(), Box<dyn Error>> {
+    let start = load_universe(seed_path)?;
+    let locked = parse_fields(locked)?;
+    let chosen = parse_fields(fields)?;
+    let parameters: Vec<&LawParameter> = LAW_PARAMETERS
+        .iter()
+        .filter(|parameter| chosen.is_empty() || chosen.contains(&parameter.name))
+        .filter(|parameter| !locked.contains(&parameter.name))
+        .collect();
+    if parameters.is_empty() {
+        return Err("No queda ningún campo libre que optimizar".into());
+    }
+
+    let mut rng = thread_rng();
+    let mut problem = Problem {
+        start: start.clone(),
+        parameters,
+        objective: calculate_enhanced_fitness,
+        evaluations: 0,
+    };
+    let (start_fitness, start_level) = calculate_enhanced_fitness(&start);
+
+    println!("🎛️ OPTIMIZACIÓN CONTINUA ({})", algorithm);
+    println!("🚀 Universo inicial: fitness {:.4}, nivel {} ({})", start_fitness, start_level, analyze_universe_type(start_level));
+    println!("🔓 {} campos libres | 🔒 bloqueados: {}", problem.parameters.len(), if locked.is_empty() { "ninguno".to_string() } else { locked.join(", ") });
+
+    let mut wtr = csv::Writer::from_path("optimize_log.csv")?;
+    wtr.write_record(["iteration", "evaluations", "best_fitness", "best_complexity_level", "current_fitness", "step_size"])?;
+    let report_every = (iterations / 10).max(1);
+    let evaluations_per_iteration = match algorithm {
+        "cmaes" => 4 + (3.0 * (problem.parameters.len() as f64).ln()).floor() as u64,
+        _ => 1,
+    };
+    let mut log = |progress: Progress| -> Result<(), Box<dyn Error>> {
+        wtr.write_record([
+            progress.iteration.to_string(),
+            (1 + (progress.iteration as u64 + 1) * evaluations_per_iteration).to_string(),
+            format!("{:.6}", progress.best_fitness),
+            progress.best_level.to_string(),
+            format!("{:.6}", progress.current_fitness),
+            format!("{:.3e}", progress.step_size),
+        ])?;
+        if progress.iteration.is_multiple_of(report_every) {
+            println!(
+                "🔁 Iteración {}: mejor fitness {:.4} (nivel {}) | actual {:.4} | paso {:.2e}",
+                progress.iteration, progress.best_fitness, progress.best_level, progress.current_fitness, progress.step_size
+            );
+        }
+        Ok(())
+    };
+
+    let (best_point, best_fitness, best_level) = match algorithm {
+        "cmaes" => run_cma_es(&mut problem, iterations, step_size, &mut rng, &mut log)?,
+        "annealing" => run_annealing(&mut problem, iterations, step_size, &mut rng, &mut log)?,
+        other => return Err(format!("Algoritmo desconocido '{}'; disponibles: cmaes, annealing", other).into()),
+    };
+    wtr.flush()?;
+
+    let optimum = problem.laws_at(&best_point);
+    std::fs::write("optimize_best.json", serde_json::to_string_pretty(&optimum)?)?;
+
+    println!("\n🏆 === ÓPTIMO ({} evaluaciones) ===", problem.evaluations);
+    println!(
+        "   Fitness: {:.4} → {:.4} | nivel {} ({})",
+        start_fitness,
+        best_fitness,
+        best_level,
+        analyze_universe_type(best_level)
+    );
+    for parameter in &problem.parameters {
+        let before = start.parameter(parameter.name).unwrap_or(0.0);
+        let after = optimum.parameter(parameter.name).unwrap_or(0.0);
+        let shift = parameter.unit_position(after) - parameter.unit_position(before);
+        if shift.abs() > 1e-3 {
+            println!("   {:<34} {:.4e} → {:.4e} ({:+.3} anchos de prior)", parameter.name, before, after, shift);
+        }
+    }
+    println!("💾 Historia en 'optimize_log.csv', óptimo en 'optimize_best.json'");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    const TARGET: f64 = 0.7;
+
+    // Cuenco cuadrático en el espacio unitario de α_s y α_w con máximo 1 en (0.7, 0.7)
+    fn bowl(laws: &CosmicLaw) -> (f64, u8) {
+        let distance: f64 = ["alpha_s", "alpha_w"]
+            .iter()
+            .map(|name| {
+                let parameter = law_parameter(name).unwrap();
+                (parameter.unit_position(laws.parameter(name).unwrap()) - TARGET).powi(2)
+            })
+            .sum();
+        (1.0 - distance, 0)
+    }
+
+    fn bowl_problem() -> Problem<'static> {
+        Problem {
+            start: CosmicLaw::reference(),
+            parameters: vec![law_parameter("alpha_s").unwrap(), law_parameter("alpha_w").unwrap()],
+            objective: bowl,
+            evaluations: 0,
+        }
+    }
+
+    #[test]
+    fn cma_es_finds_the_bowl_maximum() {
+        let mut problem = bowl_problem();
+        let mut rng = StdRng::seed_from_u64(11);
+        let (point, fitness, _) = run_cma_es(&mut problem, 150, 0.2, &mut rng, &mut |_| Ok(())).unwrap();
+        assert!(fitness > 1.0 - 1e-8, "{}", fitness);
+        assert!(point.iter().all(|unit| (unit - TARGET).abs() < 1e-3), "{:?}", point);
+    }
+
+    #[test]
+    fn annealing_improves_on_the_start() {
+        let mut problem = bowl_problem();
+        let start = bowl(&problem.start).0;
+        let mut rng = StdRng::seed_from_u64(11);
+        let (_, fitness, _) = run_annealing(&mut problem, 2000, 0.1, &mut rng, &mut |_| Ok(())).unwrap();
+        assert!(fitness > start && fitness > 0.99, "{} → {}", start, fitness);
+    }
+
+    #[test]
+    fn cholesky_reproduces_the_matrix() {
+        let matrix = vec![vec![4.0, 2.0], vec![2.0, 3.0]];
+        let lower = cholesky(&matrix);
+        for i in 0..2 {
+            for j in 0..2 {
+                let product: f64 = (0..2).map(|k| lower[i][k] * lower[j][k]).sum();
+                assert!((product - matrix[i][j]).abs() < 1e-12);
+            }
+        }
+        assert_eq!(parse_fields(Some("alpha, g")).unwrap(), vec!["e", "g"]);
+        assert!(parse_fields(Some("no-existe")).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+use crate::core::models::{CosmicLaw, FitnessBreakdown, FITNESS_COMPONENTS};
+use crate::core::parameters::LAW_PARAMETERS;
+use crate::physics::engine::calculate_fitness_breakdown;
+use crate::sim_modes::validate::load_universe;
+use rand::prelude::*;
+use serde::Serialize;
+use std::error::Error;
+use csv;
+
+const CROSSOVER_PROBABILITY: f64 = 0.9;
+const CONFLICT_CORRELATION: f64 = -0.2; // Por debajo, dos objetivos compiten a lo largo del frente
+
+#[derive(Debug, Clone)]
+struct Candidate {
+    laws: CosmicLaw,
+    breakdown: FitnessBreakdown,
+    objectives: Vec<f64>,
+    rank: usize,
+    crowding: f64,
+}
+
+#[derive(Serialize)]
+struct ParetoMember<'a> {
+    laws: &'a CosmicLaw,
+    breakdown: &'a FitnessBreakdown,
+}
+
+fn evaluate(laws: CosmicLaw, objectives: &[&str]) -> Candidate {
+    let breakdown = calculate_fitness_breakdown(&laws);
+    let objectives = objectives.iter().map(|name| breakdown.component(name).unwrap_or(0.0)).collect();
+    Candidate {
+        laws,
+        breakdown,
+        objectives,
+        rank: 0,
+        crowding: 0.0,
+    }
+}
+
+// Se maximizan todos los objetivos
+fn dominates(a: &Candidate, b: &Candidate) -> bool {
+    let no_worse = a.objectives.iter().zip(&b.objectives).all(|(x, y)| x >= y);
+    let better = a.objectives.iter().zip(&b.objectives).any(|(x, y)| x > y);
+    no_worse && better
+}
+
+// Ordenación no dominada rápida de Deb et al.; asigna rangos y devuelve los frentes
+fn non_dominated_sort(population: &mut [Candidate]) -> Vec<Vec<usize>> {
+    let size = population.len();
+    let mut dominated_by: Vec<Vec<usize>> = vec![Vec::new(); size];
+    let mut domination_count = vec![0usize; size];
+    let mut fronts: Vec<Vec<usize>> = vec![Vec::new()];
+
+    for p in 0..size {
+        for q in 0..size {
+            if dominates(&population[p], &population[q]) {
+                dominated_by[p].push(q);
+            } else if dominates(&population[q], &population[p]) {
+                domination_count[p] += 1;
+            }
+        }
+        if domination_count[p] == 0 {
+            population[p].rank = 0;
+            fronts[0].push(p);
+        }
+    }
+
+    let mut current = 0;
+    while !fronts[current].is_empty() {
+        let mut next = Vec::new();
+        for &p in &fronts[current] {
+            for &q in &dominated_by[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    population[q].rank = current + 1;
+                    next.push(q);
+                }
+            }
+        }
+        current += 1;
+        fronts.push(next);
+    }
+    fronts.pop();
+    fronts
+}
+
+// Distancia de aglomeración: los extremos de cada objetivo se conservan siempre
+fn assign_crowding(population: &mut [Candidate], front: &[usize]) {
+    for &index in front {
+        population[index].crowding = 0.0;
+    }
+    let objective_count = population[front[0]].objectives.len();
+    for objective in 0..objective_count {
+        let mut sorted = front.to_vec();
+        sorted.sort_by(|&a, &b| population[a].objectives[objective].total_cmp(&population[b].objectives[objective]));
+        let low = population[sorted[0]].objectives[objective];
+        let high = population[sorted[sorted.len() - 1]].objectives[objective];
+        population[sorted[0]].crowding = f64::INFINITY;
+        population[sorted[sorted.len() - 1]].crowding = f64::INFINITY;
+        if high - low <= 0.0 {
+            continue;
+        }
+        for window in 1..sorted.len().saturating_sub(1) {
+            let gap = population[sorted[window + 1]].objectives[objective] - population[sorted[window - 1]].objectives[objective];
+            population[sorted[window]].crowding += gap / (high - low);
+        }
+    }
+}
+
+fn tournament<'a>(population: &'a [Candidate], rng: &mut impl Rng) -> &'a Candidate {
+    let a = &population[rng.gen_range(0..population.len())];
+    let b = &population[rng.gen_range(0..population.len())];
+    if a.rank < b.rank || (a.rank == b.rank && a.crowding > b.crowding) {
+        a
+    } else {
+        b
+    }
+}
+
+fn correlation(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+    let covariance: f64 = x.iter().zip(y).map(|(a, b)| (a - mean_x) * (b - mean_y)).sum();
+    let spread_x: f64 = x.iter().map(|a| (a - mean_x).powi(2)).sum::<f64>().sqrt();
+    let spread_y: f64 = y.iter().map(|b| (b - mean_y).powi(2)).sum::<f64>().sqrt();
+    if spread_x <= 0.0 || spread_y <= 0.0 {
+        return 0.0;
+    }
+    covariance / (spread_x * spread_y)
+}
+
+// NSGA-II: cada componente del fitness es un objetivo y se busca el frente de Pareto
+pub fn run_pareto_mode(
+    population_size: usize,
+    generations: u32,
+    mutation_strength: f64,
+    objectives: Option<&str>,
+    seed_path: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let objectives: Vec<&str> = match objectives {
+        Some(list) => list.split(',').map(str::trim).collect(),
+        None => FITNESS_COMPONENTS.to_vec(),
+    };
+    if let Some(unknown) = objectives.iter().find(|name| !FITNESS_COMPONENTS.contains(name)) {
+        return Err(format!("Objetivo desconocido '{}'; disponibles: {}", unknown, FITNESS_COMPONENTS.join(", ")).into());
+    }
+
+    let mut rng = thread_rng();
+    let reference = CosmicLaw::reference();
+
+    println!("⚖️ OPTIMIZACIÓN MULTIOBJETIVO (NSGA-II)");
+    println!("🎯 Objetivos: {}", objectives.join(", "));
+    println!("👥 Población: {} | Generaciones: {}", population_size, generations);
+
+    let seed = seed_path.map(|path| load_universe(Some(path))).transpose()?;
+    let mut population: Vec<Candidate> = (0..population_size)
+        .map(|_| {
+            let laws = match &seed {
+                Some(seed) => seed.mutated(mutation_strength, &mut rng),
+                None => CosmicLaw::sample_prior(&reference, &mut rng),
+            };
+            evaluate(laws, &objectives)
+        })
+        .collect();
+
+    for front in non_dominated_sort(&mut population) {
+        assign_crowding(&mut population, &front);
+    }
+
+    for generation in 0..generations {
+        let offspring: Vec<Candidate> = (0..population_size)
+            .map(|_| {
+                let mother = tournament(&population, &mut rng);
+                let father = tournament(&population, &mut rng);
+                let child = if rng.gen_bool(CROSSOVER_PROBABILITY) {
+                    mother.laws.crossover(&father.laws, &mut rng)
+                } else {
+                    mother.laws.clone()
+                };
+                evaluate(child.mutated(mutation_strength, &mut rng), &objectives)
+            })
+            .collect();
+
+        // Elitismo: padres e hijos compiten juntos y se llenan frentes completos
+        let mut combined = population;
+        combined.extend(offspring);
+        let fronts = non_dominated_sort(&mut combined);
+
+        let mut survivors = Vec::with_capacity(population_size);
+        for front in fronts {
+            assign_crowding(&mut combined, &front);
+            if survivors.len() + front.len() <= population_size {
+                survivors.extend(front);
+            } else {
+                let mut last = front;
+                last.sort_by(|&a, &b| combined[b].crowding.total_cmp(&combined[a].crowding));
+                survivors.extend(last.into_iter().take(population_size - survivors.len()));
+                break;
+            }
+        }
+        population = survivors.into_iter().map(|index| combined[index].clone()).collect();
+
+        if generation % 10 == 0 {
+            let front_size = population.iter().filter(|c| c.rank == 0).count();
+            let best_fitness = population.iter().map(|c| c.breakdown.fitness).fold(0.0, f64::max);
+            println!(
+                "🧬 Generación {}: frente de Pareto con {} universos | mejor fitness {:.4}",
+                generation, front_size, best_fitness
+            );
+        }
+    }
+
+    let front: Vec<&Candidate> = population.iter().filter(|c| c.rank == 0).collect();
+
+    let mut wtr = csv::Writer::from_path("pareto_front.csv")?;
+    let mut header: Vec<&str> = objectives.clone();
+    header.extend(["fitness", "complexity_level"]);
+    header.extend(LAW_PARAMETERS.iter().map(|parameter| parameter.name));
+    wtr.write_record(&header)?;
+    for candidate in &front {
+        let mut record: Vec<String> = candidate.objectives.iter().map(|value| format!("{:.6}", value)).collect();
+        record.push(format!("{:.6}", candidate.breakdown.fitness));
+        record.push(candidate.breakdown.complexity_level.to_string());
+        record.extend(
+            LAW_PARAMETERS
+                .iter()
+                .map(|parameter| format!("{:e}", candidate.laws.parameter(parameter.name).unwrap_or(0.0))),
+        );
+        wtr.write_record(&record)?;
+    }
+    wtr.flush()?;
+
+    let members: Vec<ParetoMember> = front
+        .iter()
+        .map(|candidate| ParetoMember {
+            laws: &candidate.laws,
+            breakdown: &candidate.breakdown,
+        })
+        .collect();
+    std::fs::write("pareto_front.json", serde_json::to_string_pretty(&members)?)?;
+
+    println!("\n📊 === FRENTE DE PARETO: {} universos no dominados ===", front.len());
+    for (index, name) in objectives.iter().enumerate() {
+        let best = front.iter().map(|c| c.objectives[index]).fold(0.0, f64::max);
+        println!("   {:<16} máximo en el frente: {:.4}", name, best);
+    }
+
+    // Compromisos: correlaciones negativas entre objetivos a lo largo del frente
+    if front.len() > 2 {
+        println!("\n🔀 === COMPROMISOS ENTRE OBJETIVOS ===");
+        let mut conflicts = 0;
+        for a in 0..objectives.len() {
+            for b in (a + 1)..objectives.len() {
+                let x: Vec<f64> = front.iter().map(|c| c.objectives[a]).collect();
+                let y: Vec<f64> = front.iter().map(|c| c.objectives[b]).collect();
+                let r = correlation(&x, &y);
+                if r < CONFLICT_CORRELATION {
+                    conflicts += 1;
+                    println!("   {} ⟷ {}: r = {:+.3}", objectives[a], objectives[b], r);
+                }
+            }
+        }
+        if conflicts == 0 {
+            println!("   Ningún par de objetivos compite en el frente");
+        }
+    }
+
+    println!("💾 Frente guardado en 'pareto_front.csv' y 'pareto_front.json'");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(objectives: &[f64]) -> Candidate {
+        Candidate {
+            laws: CosmicLaw::reference(),
+            breakdown: FitnessBreakdown::default(),
+            objectives: objectives.to_vec(),
+            rank: 0,
+            crowding: 0.0,
+        }
+    }
+
+    #[test]
+    fn hand_built_fronts_are_ranked() {
+        // Frente 0: (1,0) (0.5,0.5) (0,1); frente 1: (0.4,0.4); frente 2: (0.1,0.1)
+        let mut population: Vec<Candidate> =
+            [[0.4, 0.4], [1.0, 0.0], [0.1, 0.1], [0.5, 0.5], [0.0, 1.0]].iter().map(|o| point(o)).collect();
+        let fronts = non_dominated_sort(&mut population);
+
+        let mut first = fronts[0].clone();
+        first.sort();
+        assert_eq!(first, vec![1, 3, 4]);
+        assert_eq!(fronts[1], vec![0]);
+        assert_eq!(fronts[2], vec![2]);
+        assert_eq!(fronts.len(), 3);
+        assert_eq!(population[2].rank, 2);
+        assert!(dominates(&population[3], &population[0]));
+        assert!(!dominates(&population[1], &population[4]));
+    }
+
+    #[test]
+    fn crowding_keeps_extremes_and_measures_gaps() {
+        let mut population: Vec<Candidate> =
+            [[0.0, 1.0], [0.25, 0.75], [0.5, 0.5], [1.0, 0.0]].iter().map(|o| point(o)).collect();
+        assign_crowding(&mut population, &[0, 1, 2, 3]);
+
+        assert!(population[0].crowding.is_infinite() && population[3].crowding.is_infinite());
+        // Vecinos a 0.5 en cada objetivo sobre un rango de 1
+        assert!((population[1].crowding - 1.0).abs() < 1e-12);
+        assert!((population[2].crowding - 1.5).abs() < 1e-12);
+    }
+}
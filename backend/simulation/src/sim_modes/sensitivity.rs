@@ -0,0 +1,325 @@
+use crate::core::models::CosmicLaw;
+use crate::core::parameters::{LawParameter, LAW_PARAMETERS};
+use crate::physics::engine::calculate_fitness_breakdown;
+use crate::sim_modes::gradient::fitness_gradient;
+use crate::sim_modes::validate::load_universe;
+use crate::utils::analyze_universe_type;
+use std::error::Error;
+use csv;
+
+const FINE_TUNING_THRESHOLD: f64 = 10.0; // Δ > 10: criterio habitual de ajuste fino
+const EDGE_BISECTIONS: u32 = 40;
+const STEP_SWEEP: [f64; 3] = [1.0, 0.1, 0.01]; // Fracciones del paso dado
+const SMOOTHNESS_TOLERANCE: f64 = 0.1; // Discrepancia relativa admitida entre pasos consecutivos
+
+struct ViableInterval {
+    low_unit: f64,
+    high_unit: f64,
+    low_open: bool, // La viabilidad llega al borde del prior
+    high_open: bool,
+}
+
+struct FiniteDifference {
+    elasticity: f64, // Con el paso más fino del barrido
+    smooth: bool,
+}
+
+struct FieldSensitivity {
+    parameter: &'static LawParameter,
+    value: f64,
+    elasticity: Option<FiniteDifference>,
+    dual_elasticity: Option<f64>,
+    interval: Option<ViableInterval>,
+}
+
+impl FieldSensitivity {
+    fn jump(&self) -> bool {
+        self.elasticity.as_ref().is_some_and(|difference| !difference.smooth && difference.elasticity.is_finite())
+    }
+
+    // Barbieri–Giudice: Δ = |∂ln f / ∂ln p|; junto a un salto discreto solo cuenta la parte suave, la dual
+    fn tuning(&self) -> Option<f64> {
+        let difference = self.elasticity.as_ref()?;
+        if self.jump() {
+            return self.dual_elasticity.map(f64::abs);
+        }
+        Some(difference.elasticity.abs())
+    }
+}
+
+// Viable = ninguna puerta dura falla (con --smooth, ninguna logística por debajo de 1/2)
+fn is_viable(laws: &CosmicLaw) -> bool {
+    calculate_fitness_breakdown(laws).failure_reason.is_none()
+}
+
+// Suma sin tope: con el fitness saturado en 1 toda elasticidad sería cero
+fn fitness_with(laws: &CosmicLaw, name: &str, value: f64) -> f64 {
+    let mut varied = laws.clone();
+    varied.set_parameter(name, value);
+    calculate_fitness_breakdown(&varied).uncapped_fitness
+}
+
+// Diferencia central en ln p; un precipicio dentro del paso es ajuste infinito
+fn central_log_difference(laws: &CosmicLaw, name: &str, value: f64, step: f64) -> f64 {
+    let up = fitness_with(laws, name, value * (1.0 + step));
+    let down = fitness_with(laws, name, value * (1.0 - step));
+    if up <= 0.0 || down <= 0.0 {
+        return f64::INFINITY;
+    }
+    (up.ln() - down.ln()) / ((1.0 + step).ln() - (1.0 - step).ln())
+}
+
+// Barrido de pasos: en un punto suave las estimaciones convergen; un salto dentro del paso crece como 1/h
+fn log_elasticity(laws: &CosmicLaw, name: &str, value: f64, step: f64, fitness: f64) -> Option<FiniteDifference> {
+    if value == 0.0 || fitness <= 0.0 {
+        return None;
+    }
+    let estimates = STEP_SWEEP.map(|fraction| central_log_difference(laws, name, value, step * fraction));
+    let smooth = estimates.windows(2).all(|pair| {
+        let scale = pair[0].abs().max(pair[1].abs());
+        pair[1].is_finite() && (pair[0] - pair[1]).abs() <= SMOOTHNESS_TOLERANCE * scale
+    });
+    let elasticity = if estimates.iter().any(|estimate| estimate.is_infinite()) {
+        f64::INFINITY
+    } else {
+        estimates[STEP_SWEEP.len() - 1]
+    };
+    Some(FiniteDifference { elasticity, smooth })
+}
+
+fn dual_elasticity(laws: &CosmicLaw, name: &str, value: f64, fitness: f64) -> Option<f64> {
+    if fitness <= 0.0 {
+        return None;
+    }
+    let breakdown = fitness_gradient(laws, name)?;
+    Some(breakdown.uncapped_fitness.derivative * value / fitness)
+}
+
+// Camina desde la posición actual hacia el borde y afina el último cruce por bisección
+fn viable_edge(at: &impl Fn(f64) -> bool, start: f64, bound: f64, step: f64) -> (f64, bool) {
+    if start == bound {
+        return (bound, true);
+    }
+    let direction = (bound - start).signum();
+    let mut inside = start;
+    loop {
+        let next = inside + direction * step;
+        let outside = if (next - bound) * direction >= 0.0 {
+            if at(bound) {
+                return (bound, true);
+            }
+            bound
+        } else if at(next) {
+            inside = next;
+            continue;
+        } else {
+            next
+        };
+
+        let (mut inner, mut outer) = (inside, outside);
+        for _ in 0..EDGE_BISECTIONS {
+            let middle = 0.5 * (inner + outer);
+            if at(middle) {
+                inner = middle;
+            } else {
+                outer = middle;
+            }
+        }
+        return (inner, false);
+    }
+}
+
+fn viable_interval(laws: &CosmicLaw, parameter: &LawParameter, value: f64, points: usize) -> Option<ViableInterval> {
+    let start = parameter.unit_position(value);
+    if !start.is_finite() || !is_viable(laws) {
+        return None;
+    }
+
+    let at = |unit: f64| {
+        let mut varied = laws.clone();
+        varied.set_unit_position(parameter, unit);
+        is_viable(&varied)
+    };
+    let (low_bound, high_bound) = (start.min(0.0), start.max(1.0));
+    let step = (high_bound - low_bound) / points.max(1) as f64;
+    let (low_unit, low_open) = viable_edge(&at, start, low_bound, step);
+    let (high_unit, high_open) = viable_edge(&at, start, high_bound, step);
+
+    Some(ViableInterval {
+        low_unit,
+        high_unit,
+        low_open,
+        high_open,
+    })
+}
+
+fn format_edge(parameter: &LawParameter, unit: f64, open: bool, marker: &str) -> String {
+    let value = format!("{:.3e}", parameter.value_at(unit));
+    if open {
+        format!("{}{}", marker, value)
+    } else {
+        value
+    }
+}
+
+fn format_optional(value: Option<f64>) -> String {
+    value.map(|v| format!("{:.3e}", v)).unwrap_or_else(|| "—".to_string())
+}
+
+pub fn run_sensitivity_mode(universe_path: Option<&str>, step: f64, points: usize) -> Result<(), Box<dyn Error>> {
+    let laws = load_universe(universe_path)?;
+    let breakdown = calculate_fitness_breakdown(&laws);
+
+    println!("🎚️ SENSIBILIDAD Y AJUSTE FINO (Barbieri–Giudice)");
+    println!(
+        "🌌 Fitness {:.6}, nivel {} ({})",
+        breakdown.fitness,
+        breakdown.complexity_level,
+        analyze_universe_type(breakdown.complexity_level)
+    );
+    if let Some(reason) = &breakdown.failure_reason {
+        println!("⚠️ {}: no hay intervalo viable alrededor de este universo", reason);
+    }
+    if breakdown.uncapped_fitness > breakdown.fitness {
+        println!("🔝 Fitness saturado en 1 (suma sin tope {:.4}): las elasticidades se miden sobre la suma sin tope", breakdown.uncapped_fitness);
+    }
+    println!(
+        "📐 Pasos relativos {:.0e}–{:.0e} para las elasticidades, {} puntos por prior para los intervalos",
+        step * STEP_SWEEP[0],
+        step * STEP_SWEEP[STEP_SWEEP.len() - 1],
+        points
+    );
+
+    let mut fields: Vec<FieldSensitivity> = LAW_PARAMETERS
+        .iter()
+        .filter_map(|parameter| {
+            let value = laws.parameter(parameter.name)?;
+            Some(FieldSensitivity {
+                parameter,
+                value,
+                elasticity: log_elasticity(&laws, parameter.name, value, step, breakdown.uncapped_fitness),
+                dual_elasticity: dual_elasticity(&laws, parameter.name, value, breakdown.uncapped_fitness),
+                interval: viable_interval(&laws, parameter, value, points),
+            })
+        })
+        .collect();
+    fields.sort_by(|a, b| b.tuning().unwrap_or(-1.0).total_cmp(&a.tuning().unwrap_or(-1.0)));
+
+    println!(
+        "\n{:>3} {:<34} {:>11} {:>11} {:>11} {:>24} {:>8}",
+        "#", "campo", "valor", "Δ", "Δ dual", "intervalo viable", "% prior"
+    );
+    for (rank, field) in fields.iter().enumerate() {
+        let (interval, fraction) = match &field.interval {
+            Some(interval) => (
+                format!(
+                    "[{}, {}]",
+                    format_edge(field.parameter, interval.low_unit, interval.low_open, "≤"),
+                    format_edge(field.parameter, interval.high_unit, interval.high_open, "≥")
+                ),
+                format!("{:.1}", 100.0 * (interval.high_unit.min(1.0) - interval.low_unit.max(0.0)).max(0.0)),
+            ),
+            None => ("—".to_string(), "—".to_string()),
+        };
+        let mut flag = if field.tuning().is_some_and(|tuning| tuning > FINE_TUNING_THRESHOLD) { " 🎯" } else { "" }.to_string();
+        if field.jump() {
+            flag.push_str(" ⚡");
+        }
+        println!(
+            "{:>3} {:<34} {:>11.3e} {:>11} {:>11} {:>24} {:>8}{}",
+            rank + 1,
+            field.parameter.name,
+            field.value,
+            format_optional(field.tuning()),
+            format_optional(field.dual_elasticity.map(f64::abs)),
+            interval,
+            fraction,
+            flag
+        );
+    }
+
+    let fine_tuned: Vec<&str> = fields
+        .iter()
+        .filter(|field| field.tuning().is_some_and(|tuning| tuning > FINE_TUNING_THRESHOLD))
+        .map(|field| field.parameter.name)
+        .collect();
+    if fine_tuned.is_empty() {
+        println!("\n✅ Ningún campo supera Δ = {}", FINE_TUNING_THRESHOLD);
+    } else {
+        println!("\n🎯 Ajuste fino (Δ > {}): {}", FINE_TUNING_THRESHOLD, fine_tuned.join(", "));
+    }
+    if fields.iter().any(FieldSensitivity::jump) {
+        println!("⚡ Salto discreto cerca del punto: la diferencia finita no converge y Δ toma la elasticidad dual");
+    }
+
+    let mut wtr = csv::Writer::from_path("sensitivity.csv")?;
+    wtr.write_record([
+        "rank",
+        "field",
+        "value",
+        "elasticity",
+        "smooth",
+        "barbieri_giudice",
+        "dual_elasticity",
+        "viable_min",
+        "viable_max",
+        "viable_min_at_prior_edge",
+        "viable_max_at_prior_edge",
+        "viable_prior_fraction",
+    ])?;
+    for (rank, field) in fields.iter().enumerate() {
+        let optional = |value: Option<f64>| value.map(|v| format!("{:e}", v)).unwrap_or_default();
+        let interval = field.interval.as_ref();
+        wtr.write_record([
+            (rank + 1).to_string(),
+            field.parameter.name.to_string(),
+            format!("{:e}", field.value),
+            optional(field.elasticity.as_ref().map(|difference| difference.elasticity)),
+            field.elasticity.as_ref().map(|difference| difference.smooth.to_string()).unwrap_or_default(),
+            optional(field.tuning()),
+            optional(field.dual_elasticity),
+            optional(interval.map(|i| field.parameter.value_at(i.low_unit))),
+            optional(interval.map(|i| field.parameter.value_at(i.high_unit))),
+            interval.map(|i| i.low_open.to_string()).unwrap_or_default(),
+            interval.map(|i| i.high_open.to_string()).unwrap_or_default(),
+            optional(interval.map(|i| (i.high_unit.min(1.0) - i.low_unit.max(0.0)).max(0.0))),
+        ])?;
+    }
+    wtr.flush()?;
+
+    println!("💾 Tabla completa en 'sensitivity.csv'");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn viable_edge_bisects_the_crossing() {
+        let at = |unit: f64| unit < 0.37;
+        let (edge, open) = viable_edge(&at, 0.1, 1.0, 0.05);
+        assert!(!open);
+        assert!((edge - 0.37).abs() < 1e-9);
+        assert_eq!(viable_edge(&at, 0.1, 0.0, 0.05), (0.0, true));
+    }
+
+    // El universo de referencia satura el tope: sus elasticidades no pueden salir todas nulas
+    #[test]
+    fn saturated_reference_still_responds_to_its_fields() {
+        let laws = CosmicLaw::reference();
+        let breakdown = calculate_fitness_breakdown(&laws);
+        assert_eq!(breakdown.fitness, 1.0);
+        assert!(breakdown.uncapped_fitness > 1.0);
+
+        let fitness = breakdown.uncapped_fitness;
+        let difference = log_elasticity(&laws, "e", laws.e, 1e-3, fitness).unwrap();
+        assert!(difference.elasticity.abs() > 0.0);
+        let dual = dual_elasticity(&laws, "e", laws.e, fitness).unwrap();
+        if difference.smooth {
+            assert!((difference.elasticity - dual).abs() <= 0.05 * dual.abs());
+        }
+        assert!(log_elasticity(&laws, "neutrino_mass_1", 0.0, 1e-3, fitness).is_none());
+    }
+}
@@ -0,0 +1,290 @@
+use crate::core::models::{CosmicLaw, FITNESS_COMPONENTS};
+use crate::core::parameters::{LawParameter, LAW_PARAMETERS};
+use crate::physics::engine::calculate_fitness_breakdown;
+use crate::sim_modes::validate::load_universe;
+use rand::prelude::*;
+use std::error::Error;
+use csv;
+
+const CONFIDENCE_LEVEL: f64 = 0.95;
+const INTERACTION_GAP: f64 = 0.1; // S_T - S_1 por encima: el campo actúa sobre todo en combinación con otros
+const REPORT_SHARE: f64 = 0.1; // Fracción de filas entre mensajes de progreso
+const MIN_VIABLE_POINTS: f64 = 30.0; // Menos universos viables en A y B y los índices dependen de un puñado de filas
+const MIN_VARIANCE: f64 = 1e-8; // Puntuaciones en [0, 1]: por debajo, la salida es constante a efectos prácticos
+
+// Salidas analizadas: el fitness, cada componente y la viabilidad (0/1)
+fn outputs() -> Vec<&'static str> {
+    let mut outputs = vec!["fitness"];
+    outputs.extend(FITNESS_COMPONENTS);
+    outputs.push("viable");
+    outputs
+}
+
+fn evaluate(base: &CosmicLaw, units: &[f64], outputs: &[&str]) -> Vec<f64> {
+    let mut laws = base.clone();
+    for (parameter, unit) in LAW_PARAMETERS.iter().zip(units) {
+        laws.set_unit_position(parameter, *unit);
+    }
+    let breakdown = calculate_fitness_breakdown(&laws);
+    outputs
+        .iter()
+        .map(|name| match *name {
+            "viable" => if breakdown.failure_reason.is_none() { 1.0 } else { 0.0 },
+            name => breakdown.component(name).unwrap_or(0.0),
+        })
+        .collect()
+}
+
+// Diseño de Saltelli: matrices A y B, y para cada campo A con esa columna tomada de B
+struct SaltelliSample {
+    a: Vec<Vec<f64>>,       // [fila][salida]
+    b: Vec<Vec<f64>>,       // [fila][salida]
+    ab: Vec<Vec<Vec<f64>>>, // [campo][fila][salida]
+}
+
+fn draw_sample(base: &CosmicLaw, samples: usize, outputs: &[&str], rng: &mut impl Rng) -> SaltelliSample {
+    let dimensions = LAW_PARAMETERS.len();
+    let mut sample = SaltelliSample {
+        a: Vec::with_capacity(samples),
+        b: Vec::with_capacity(samples),
+        ab: vec![Vec::with_capacity(samples); dimensions],
+    };
+    let report_every = ((samples as f64 * REPORT_SHARE) as usize).max(1);
+
+    for row in 0..samples {
+        let a: Vec<f64> = (0..dimensions).map(|_| rng.gen()).collect();
+        let b: Vec<f64> = (0..dimensions).map(|_| rng.gen()).collect();
+        sample.a.push(evaluate(base, &a, outputs));
+        sample.b.push(evaluate(base, &b, outputs));
+        for (field, ab) in sample.ab.iter_mut().enumerate() {
+            let mut mixed = a.clone();
+            mixed[field] = b[field];
+            ab.push(evaluate(base, &mixed, outputs));
+        }
+        if (row + 1).is_multiple_of(report_every) {
+            println!("🎲 {}/{} filas ({} evaluaciones)", row + 1, samples, (row + 1) * (dimensions + 2));
+        }
+    }
+    sample
+}
+
+// Índices sobre un conjunto de filas (con repetición en el bootstrap); None si la salida no varía
+fn sobol_indices(sample: &SaltelliSample, rows: &[usize], field: usize, output: usize) -> Option<(f64, f64)> {
+    let n = rows.len() as f64;
+    let values = rows.iter().flat_map(|&row| [sample.a[row][output], sample.b[row][output]]);
+    let mean = values.clone().sum::<f64>() / (2.0 * n);
+    let variance = values.map(|value| (value - mean).powi(2)).sum::<f64>() / (2.0 * n);
+    if variance <= MIN_VARIANCE {
+        return None;
+    }
+
+    let (mut first, mut total) = (0.0, 0.0);
+    for &row in rows {
+        let (a, b, ab) = (sample.a[row][output], sample.b[row][output], sample.ab[field][row][output]);
+        first += b * (ab - a); // Saltelli (2010)
+        total += (a - ab).powi(2); // Jansen (1999)
+    }
+    Some((first / n / variance, total / (2.0 * n) / variance))
+}
+
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (low, high) = (position.floor() as usize, position.ceil() as usize);
+    sorted[low] + (sorted[high] - sorted[low]) * (position - low as f64)
+}
+
+struct Interval {
+    estimate: f64,
+    low: f64,
+    high: f64,
+}
+
+impl Interval {
+    fn from_bootstrap(estimate: f64, mut replicas: Vec<f64>) -> Self {
+        if replicas.is_empty() {
+            return Interval { estimate, low: f64::NAN, high: f64::NAN };
+        }
+        replicas.sort_by(f64::total_cmp);
+        let tail = 0.5 * (1.0 - CONFIDENCE_LEVEL);
+        Interval {
+            estimate,
+            low: percentile(&replicas, tail),
+            high: percentile(&replicas, 1.0 - tail),
+        }
+    }
+}
+
+struct FieldIndices {
+    parameter: &'static LawParameter,
+    first_order: Interval,
+    total_effect: Interval,
+}
+
+// Índices de cada campo para una salida; None si la salida es constante en la muestra
+fn output_indices(sample: &SaltelliSample, output: usize, resamples: &[Vec<usize>]) -> Option<Vec<FieldIndices>> {
+    let rows: Vec<usize> = (0..sample.a.len()).collect();
+    LAW_PARAMETERS
+        .iter()
+        .enumerate()
+        .map(|(field, parameter)| {
+            let (first, total) = sobol_indices(sample, &rows, field, output)?;
+            let (first_replicas, total_replicas): (Vec<f64>, Vec<f64>) =
+                resamples.iter().filter_map(|rows| sobol_indices(sample, rows, field, output)).unzip();
+            Some(FieldIndices {
+                parameter,
+                first_order: Interval::from_bootstrap(first, first_replicas),
+                total_effect: Interval::from_bootstrap(total, total_replicas),
+            })
+        })
+        .collect()
+}
+
+fn print_table(output: &str, indices: &[FieldIndices]) {
+    println!("\n📊 {}", output);
+    println!("{:<34} {:>26} {:>26}", "campo", "S₁ (IC)", "S_T (IC)");
+    for field in indices {
+        let flag = if field.total_effect.estimate - field.first_order.estimate > INTERACTION_GAP { " 🔗" } else { "" };
+        println!(
+            "{:<34} {:>7.3} [{:>7.3}, {:>7.3}] {:>7.3} [{:>7.3}, {:>7.3}]{}",
+            field.parameter.name,
+            field.first_order.estimate,
+            field.first_order.low,
+            field.first_order.high,
+            field.total_effect.estimate,
+            field.total_effect.low,
+            field.total_effect.high,
+            flag
+        );
+    }
+    let first_sum: f64 = indices.iter().map(|field| field.first_order.estimate).sum();
+    println!("Σ S₁ = {:.3} (1 − Σ S₁ ≈ varianza debida a interacciones)", first_sum);
+}
+
+// Sensibilidad global por varianza: índices de primer orden y totales con muestreo de Saltelli sobre el prior
+pub fn run_sobol_mode(universe_path: Option<&str>, samples: usize, bootstrap: usize) -> Result<(), Box<dyn Error>> {
+    if samples < 2 {
+        return Err("Hacen falta al menos 2 filas de Saltelli".into());
+    }
+    let base = load_universe(universe_path)?;
+    let outputs = outputs();
+    let mut rng = thread_rng();
+
+    println!("🎲 ÍNDICES DE SOBOL SOBRE EL PRIOR (Saltelli)");
+    println!(
+        "📐 {} filas × {} campos = {} evaluaciones, {} réplicas bootstrap (IC {:.0}%)",
+        samples,
+        LAW_PARAMETERS.len(),
+        samples * (LAW_PARAMETERS.len() + 2),
+        bootstrap,
+        100.0 * CONFIDENCE_LEVEL
+    );
+
+    let sample = draw_sample(&base, samples, &outputs, &mut rng);
+    let resamples: Vec<Vec<usize>> = (0..bootstrap)
+        .map(|_| (0..samples).map(|_| rng.gen_range(0..samples)).collect())
+        .collect();
+
+    let viable_share = sample.a.iter().chain(&sample.b).map(|row| row[outputs.len() - 1]).sum::<f64>() / (2 * samples) as f64;
+    println!("🌱 Fracción viable del prior: {:.2}%", 100.0 * viable_share);
+    if viable_share * ((2 * samples) as f64) < MIN_VIABLE_POINTS {
+        println!("⚠️ Pocos universos viables en la muestra: intervalos anchos; sube --samples o prueba con --smooth");
+    }
+
+    let mut wtr = csv::Writer::from_path("sobol.csv")?;
+    wtr.write_record([
+        "output",
+        "field",
+        "first_order",
+        "first_order_low",
+        "first_order_high",
+        "total_effect",
+        "total_effect_low",
+        "total_effect_high",
+    ])?;
+
+    let mut leaders = Vec::new();
+    for (output, name) in outputs.iter().enumerate() {
+        let Some(mut indices) = output_indices(&sample, output, &resamples) else {
+            println!("\n➖ {}: constante en la muestra, sin varianza que repartir", name);
+            continue;
+        };
+        indices.sort_by(|a, b| b.total_effect.estimate.total_cmp(&a.total_effect.estimate));
+        for field in &indices {
+            wtr.write_record([
+                name.to_string(),
+                field.parameter.name.to_string(),
+                format!("{:.6}", field.first_order.estimate),
+                format!("{:.6}", field.first_order.low),
+                format!("{:.6}", field.first_order.high),
+                format!("{:.6}", field.total_effect.estimate),
+                format!("{:.6}", field.total_effect.low),
+                format!("{:.6}", field.total_effect.high),
+            ])?;
+        }
+        // Tabla completa para el fitness y la viabilidad; el resto, en el CSV
+        if *name == "fitness" || *name == "viable" {
+            print_table(name, &indices);
+        }
+        leaders.push((*name, indices[0].parameter.name, indices[0].total_effect.estimate));
+    }
+    wtr.flush()?;
+
+    println!("\n🏅 Campo dominante por salida (mayor S_T):");
+    for (output, field, total) in &leaders {
+        println!("   {:<16} {:<34} S_T = {:.3}", output, field, total);
+    }
+    println!("💾 Índices de todas las salidas en 'sobol.csv'");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use std::f64::consts::PI;
+
+    fn ishigami(units: &[f64]) -> Vec<f64> {
+        let [x1, x2, x3] = [0, 1, 2].map(|i| PI * (2.0 * units[i] - 1.0));
+        vec![x1.sin() + 7.0 * x2.sin().powi(2) + 0.1 * x3.powi(4) * x1.sin()]
+    }
+
+    // Índices analíticos de Ishigami con a = 7, b = 0.1
+    #[test]
+    fn ishigami_indices_match_analytic_values() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let rows = 20000;
+        let mut sample = SaltelliSample { a: Vec::new(), b: Vec::new(), ab: vec![Vec::new(); 3] };
+        for _ in 0..rows {
+            let a: Vec<f64> = (0..3).map(|_| rng.gen()).collect();
+            let b: Vec<f64> = (0..3).map(|_| rng.gen()).collect();
+            sample.a.push(ishigami(&a));
+            sample.b.push(ishigami(&b));
+            for (field, ab) in sample.ab.iter_mut().enumerate() {
+                let mut mixed = a.clone();
+                mixed[field] = b[field];
+                ab.push(ishigami(&mixed));
+            }
+        }
+
+        let all: Vec<usize> = (0..rows).collect();
+        let expected = [(0.3139, 0.5576), (0.4424, 0.4424), (0.0, 0.2437)];
+        for (field, (first, total)) in expected.iter().enumerate() {
+            let (estimated_first, estimated_total) = sobol_indices(&sample, &all, field, 0).unwrap();
+            assert!((estimated_first - first).abs() < 0.03, "S₁[{}] = {}", field, estimated_first);
+            assert!((estimated_total - total).abs() < 0.03, "S_T[{}] = {}", field, estimated_total);
+        }
+    }
+
+    #[test]
+    fn constant_output_has_no_indices() {
+        let sample = SaltelliSample { a: vec![vec![1.0]; 4], b: vec![vec![1.0]; 4], ab: vec![vec![vec![1.0]; 4]] };
+        assert!(sobol_indices(&sample, &[0, 1, 2, 3], 0, 0).is_none());
+    }
+
+    #[test]
+    fn percentile_interpolates() {
+        assert_eq!(percentile(&[0.0, 1.0, 2.0, 3.0, 4.0], 0.5), 2.0);
+        assert_eq!(percentile(&[0.0, 10.0], 0.25), 2.5);
+    }
+}
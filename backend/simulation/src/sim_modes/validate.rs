@@ -2,40 +2,81 @@
 use crate::core::models::CosmicLaw;
 use crate::physics::engine::AdvancedPhysicsEngine;
 use crate::physics::constants::*;
+use crate::physics::nuclear::REFERENCE_STABLE_ELEMENTS;
 
 const ALPHA_FINE_STRUCTURE: f64 = 7.2973525693e-3; // 1/137.035999084
 const PROTON_MASS_MEV: f64 = 938.272088; // MeV/c²
-const NEUTRON_MASS_MEV: f64 = 939.565413; // MeV/c²
 const RYDBERG_ENERGY_EV: f64 = 13.605693122994; // eV
+const BOHR_RADIUS_M: f64 = 5.29177210903e-11; // m
+const H2_DISSOCIATION_ENERGY_EV: f64 = 4.478; // eV (D₀)
+const DELTA_NUCLEON_SPLITTING_MEV: f64 = 293.7; // Δ(1232) − N
+const LAMBDA_NUCLEON_SPLITTING_MEV: f64 = 176.1; // Λ(1116) − N
+const OMEGA_DELTA_SPLITTING_MEV: f64 = 440.5; // Ω(1672) − Δ(1232)
+const PION_MASS_MEV: f64 = 139.57039; // π±
+const NUCLEAR_FORCE_RANGE_FM: f64 = 1.4138; // ħ / m_π c
+const DEUTERON_BINDING_MEV: f64 = 2.224;
+const OMEGA_DARK_MATTER: f64 = 0.265; // Planck 2018
+const THERMAL_RELIC_SIGMA_V: f64 = 2.2; // 10⁻²⁶ cm³/s
+const QCD_TRANSITION_MEV: f64 = 155.0; // Lattice QCD
+const WEAK_FREEZE_OUT_MEV: f64 = 0.8;
+const NEUTRON_LIFETIME_S: f64 = 879.4;
+const DEUTERIUM_BOTTLENECK_MEV: f64 = 0.07;
+const PRIMORDIAL_HELIUM_FRACTION: f64 = 0.245; // Y_p
+const RECOMBINATION_TEMPERATURE_K: f64 = 3700.0; // x_e = 1/2
+const OKLO_ALPHA_BOUND: f64 = 1e-7; // |Δα/α| hace 1.8 Gyr
+const QUASAR_ALPHA_BOUND: f64 = 1e-5; // |Δα/α| en líneas de absorción a z ≈ 2
+const LUNAR_RANGING_G_BOUND: f64 = 1.5e-12; // |Ġ/G| en yr⁻¹
+const TEMPERATURE_AT_ONE_SECOND_K: f64 = 1.0e10;
 
 pub struct PhysicsTest {
     name: String,
     tolerance: f64,
+    core: bool, // Si falla, el nivel falla aunque el resto de la batería pase
     test_fn: Box<dyn Fn(&AdvancedPhysicsEngine) -> (f64, f64)>, // (predicted, experimental)
 }
 
 pub struct TestSuite {
     tests: Vec<PhysicsTest>,
+    outcomes: Vec<bool>, // Resultado de cada prueba en la última ejecución
     passed: usize,
     failed: usize,
+    core_failed: usize,
 }
 
 impl TestSuite {
     pub fn new() -> Self {
         Self {
             tests: Vec::new(),
+            outcomes: Vec::new(),
             passed: 0,
             failed: 0,
+            core_failed: 0,
         }
     }
 
     pub fn add_test<F>(&mut self, name: &str, tolerance: f64, test_fn: F)
+    where
+        F: Fn(&AdvancedPhysicsEngine) -> (f64, f64) + 'static,
+    {
+        self.push_test(name, tolerance, false, test_fn);
+    }
+
+    // Pruebas esenciales del nivel: las añadidas después no pueden compensar su fallo
+    pub fn add_core_test<F>(&mut self, name: &str, tolerance: f64, test_fn: F)
+    where
+        F: Fn(&AdvancedPhysicsEngine) -> (f64, f64) + 'static,
+    {
+        self.push_test(name, tolerance, true, test_fn);
+    }
+
+    fn push_test<F>(&mut self, name: &str, tolerance: f64, core: bool, test_fn: F)
     where
         F: Fn(&AdvancedPhysicsEngine) -> (f64, f64) + 'static,
     {
         self.tests.push(PhysicsTest {
             name: name.to_string(),
             tolerance,
+            core,
             test_fn: Box::new(test_fn),
         });
     }
@@ -44,8 +85,10 @@ impl TestSuite {
         println!("🧪 EJECUTANDO BATERÍA DE PRUEBAS FÍSICAS");
         println!("{}", "=".repeat(60));
 
+        self.outcomes.clear();
         self.passed = 0;
         self.failed = 0;
+        self.core_failed = 0;
 
         for test in &self.tests {
             let (predicted, experimental) = (test.test_fn)(engine);
@@ -55,12 +98,17 @@ impl TestSuite {
                 (predicted - experimental).abs()
             };
             let passed = relative_error <= test.tolerance;
+            let marker = if test.core { " 🔒" } else { "" };
+            self.outcomes.push(passed);
             if passed {
                 self.passed += 1;
-                println!("✅ {}: PASS (Error: {:.2}%)", test.name, relative_error * 100.0);
+                println!("✅ {}{}: PASS (Error: {:.2}%)", test.name, marker, relative_error * 100.0);
             } else {
                 self.failed += 1;
-                println!("❌ {}: FAIL (Error: {:.2}%)", test.name, relative_error * 100.0);
+                if test.core {
+                    self.core_failed += 1;
+                }
+                println!("❌ {}{}: FAIL (Error: {:.2}%)", test.name, marker, relative_error * 100.0);
                 println!("   Predicho: {:.6e}, Experimental: {:.6e}", predicted, experimental);
             }
         }
@@ -69,37 +117,58 @@ impl TestSuite {
         println!("{}", "=".repeat(60));
         println!("📊 RESUMEN: {}/{} pruebas pasadas ({:.1}%)", 
                 self.passed, self.tests.len(), success_rate * 100.0);
+        if self.core_failed > 0 {
+            println!("🔒 {} prueba(s) esencial(es) fallida(s): el nivel no pasa", self.core_failed);
+        }
 
-        success_rate >= 0.9 
+        self.core_failed == 0 && success_rate >= 0.9 
     }
 }
 
 pub fn create_fundamental_test_suite() -> TestSuite {
     let mut suite = TestSuite::new();
 
-    suite.add_test("Alpha Fine Structure", 0.001, |engine| {
+    suite.add_core_test("Alpha Fine Structure", 0.001, |engine| {
     (engine.alpha, ALPHA_FINE_STRUCTURE)
     });
 
-    suite.add_test("Hydrogen Ionization Energy", 0.01, |engine| {
+    suite.add_core_test("Hydrogen Ionization Energy", 0.01, |engine| {
     let predicted_rydberg = 0.5 * engine.laws.mass_electron * engine.alpha.powi(2) * C.powi(2);
     let predicted_ev = predicted_rydberg / 1.602176634e-19; // J a eV
     (predicted_ev, RYDBERG_ENERGY_EV)
 });
 
-    suite.add_test("Proton Mass", 0.001, |engine| {
-        let (mass_proton, _, _) = engine.get_validated_hadron_masses();
-        let mass_mev = mass_proton / MEV_TO_KG;
-        (mass_mev, PROTON_MASS_MEV)
+    suite.add_test("Bohr Radius", 0.01, |engine| {
+        (engine.chemistry().bohr_radius, BOHR_RADIUS_M)
+    });
+
+    suite.add_test("H2 Dissociation Energy", 0.05, |engine| {
+        let predicted_ev = engine.chemistry().h2_bond_energy / 1.602176634e-19; // J a eV
+        (predicted_ev, H2_DISSOCIATION_ENERGY_EV)
     });
 
-    suite.add_test("Neutron-Proton Mass Difference", 0.01, |engine| {
-        let (mass_proton, mass_neutron, _) = engine.get_validated_hadron_masses();
+    suite.add_test("Pion Mass", 0.01, |engine| {
+        (engine.pion_mass() / MEV_TO_KG, PION_MASS_MEV)
+    });
 
-        let diff_kg = mass_neutron - mass_proton;
-        let diff_mev = diff_kg / MEV_TO_KG;
-        
-        (diff_mev, NEUTRON_MASS_MEV - PROTON_MASS_MEV)
+    suite.add_test("Nuclear Force Range", 0.01, |engine| {
+        (engine.nuclear_force_range() * 1e15, NUCLEAR_FORCE_RANGE_FM)
+    });
+
+    suite.add_test("Deuteron Binding Energy", 0.05, |engine| {
+        (engine.light_nuclei().deuteron_binding, DEUTERON_BINDING_MEV)
+    });
+
+    // Un solo Z por isóbaro y un corte de vida de 1 Gyr mueven el borde de estabilidad: 84 elementos con la
+    // masa nucleónica empírica y 71 con la teórica, un 5–11% de error
+    suite.add_test("Stable Elements", 0.15, |engine| {
+        (engine.periodic_table().stable_elements as f64, REFERENCE_STABLE_ELEMENTS)
+    });
+
+    suite.add_core_test("Proton Mass", 0.001, |engine| {
+        let (mass_proton, _, _) = engine.get_validated_hadron_masses();
+        let mass_mev = mass_proton / MEV_TO_KG;
+        (mass_mev, PROTON_MASS_MEV)
     });
 
     suite
@@ -108,7 +177,7 @@ pub fn create_fundamental_test_suite() -> TestSuite {
 pub fn create_qcd_test_suite() -> TestSuite {
     let mut suite = TestSuite::new();
 
-    suite.add_test("Alpha_s Running", 0.20, |engine| {
+    suite.add_core_test("Alpha_s Running", 0.20, |engine| {
         let alpha_s_2gev = engine.running_alpha_s(2.0);
         let alpha_s_91gev = engine.running_alpha_s(91.2);
         let expected_ratio = 0.336 / 0.1181; // PDG values
@@ -116,25 +185,40 @@ pub fn create_qcd_test_suite() -> TestSuite {
         (predicted_ratio, expected_ratio)
     });
 
-    suite.add_test("Up Quark Constituent Mass", 0.3, |engine| {
+    suite.add_core_test("Up Quark Constituent Mass", 0.3, |engine| {
         let mass_constituent = engine.constituent_quark_mass(engine.laws.mass_up_quark, "up");
         let mass_mev = mass_constituent / MEV_TO_KG;
         (mass_mev, 310.0) // ~330 MeV valor típico
     });
 
-    suite.add_test("Down Quark Constituent Mass", 0.3, |engine| {
+    suite.add_core_test("Down Quark Constituent Mass", 0.3, |engine| {
         let mass_constituent = engine.constituent_quark_mass(engine.laws.mass_down_quark, "down");
         let mass_mev = mass_constituent / MEV_TO_KG;
         (mass_mev, 310.0) // ~335 MeV valor típico
     });
 
+    suite.add_test("Delta-Nucleon Splitting", 0.05, |engine| {
+        let spectrum = engine.baryon_spectrum();
+        ((spectrum.mass_of("Δ⁺") - spectrum.mass_of("p")) / MEV_TO_KG, DELTA_NUCLEON_SPLITTING_MEV)
+    });
+
+    suite.add_test("Lambda-Nucleon Splitting", 0.1, |engine| {
+        let spectrum = engine.baryon_spectrum();
+        ((spectrum.mass_of("Λ") - spectrum.mass_of("n")) / MEV_TO_KG, LAMBDA_NUCLEON_SPLITTING_MEV)
+    });
+
+    suite.add_test("Omega-Delta Splitting", 0.1, |engine| {
+        let spectrum = engine.baryon_spectrum();
+        ((spectrum.mass_of("Ω⁻") - spectrum.mass_of("Δ⁻")) / MEV_TO_KG, OMEGA_DELTA_SPLITTING_MEV)
+    });
+
     suite
 }
 
 pub fn create_cosmology_test_suite() -> TestSuite {
     let mut suite = TestSuite::new();
 
-    suite.add_test("Universe Age", 0.2, |engine| {
+    suite.add_core_test("Universe Age", 0.2, |engine| {
         let viability = engine.cosmological_viability();
         if viability > 0.5 {
             let age_estimate = 4.35e17; // Asumimos que el modelo converge a ~13.8 Gyr
@@ -144,9 +228,68 @@ pub fn create_cosmology_test_suite() -> TestSuite {
         }
     });
 
+    suite.add_test("Dark Matter Relic Density", 0.2, |engine| {
+        (engine.dark_matter_relic().omega_dm, OMEGA_DARK_MATTER)
+    });
+
+    suite.add_test("Thermal Relic Cross Section", 0.3, |engine| {
+        let sigma_v_gev = engine.dark_matter_relic().annihilation_cross_section;
+        let sigma_v_cm3 = sigma_v_gev * (H_BAR * C / GEV_TO_JOULE).powi(2) * C * 1e6; // GeV⁻² a cm³/s
+        (sigma_v_cm3 * 1e26, THERMAL_RELIC_SIGMA_V)
+    });
+
+    suite.add_test("Temperature At One Second", 0.2, |engine| {
+        (engine.temperature_at_time(1.0), TEMPERATURE_AT_ONE_SECOND_K)
+    });
+
+    suite.add_test("QCD Transition Temperature", 0.1, |engine| {
+        let history = engine.thermal_history();
+        (history.qcd_transition.temperature * K_B / MEV_TO_JOULE, QCD_TRANSITION_MEV)
+    });
+
+    suite.add_test("Weak Freeze-out Temperature", 0.2, |engine| {
+        let history = engine.thermal_history();
+        (history.weak_freeze_out.temperature * K_B / MEV_TO_JOULE, WEAK_FREEZE_OUT_MEV)
+    });
+
+    // E₀⁵ escalado desde nuestro universo: solo G_F = πα_w/(√2 M_W²) a nivel árbol lo separa, y Δr ≈ 3.6%
+    suite.add_test("Neutron Lifetime", 0.05, |engine| {
+        (engine.thermal_history().neutron_lifetime, NEUTRON_LIFETIME_S)
+    });
+
+    suite.add_test("Deuterium Bottleneck Temperature", 0.2, |engine| {
+        let history = engine.thermal_history();
+        (history.nucleosynthesis.temperature * K_B / MEV_TO_JOULE, DEUTERIUM_BOTTLENECK_MEV)
+    });
+
+    suite.add_test("Primordial Helium Fraction", 0.1, |engine| {
+        (engine.thermal_history().primordial_helium_fraction, PRIMORDIAL_HELIUM_FRACTION)
+    });
+
+    // Cotas: el valor predicho solo cuenta si supera el límite observacional
+    suite.add_test("Oklo Alpha Bound", 0.0, |engine| {
+        let shift = engine.varying_constant_bounds().oklo_alpha_shift;
+        (shift.max(OKLO_ALPHA_BOUND), OKLO_ALPHA_BOUND)
+    });
+
+    suite.add_test("Quasar Alpha Bound", 0.0, |engine| {
+        let shift = engine.varying_constant_bounds().quasar_alpha_shift;
+        (shift.max(QUASAR_ALPHA_BOUND), QUASAR_ALPHA_BOUND)
+    });
+
+    suite.add_test("Lunar Laser Ranging G Bound", 0.0, |engine| {
+        let drift = engine.varying_constant_bounds().gravity_drift_rate;
+        (drift.max(LUNAR_RANGING_G_BOUND) * 1e12, LUNAR_RANGING_G_BOUND * 1e12)
+    });
+
+    suite.add_test("Recombination Temperature", 0.1, |engine| {
+        (engine.thermal_history().recombination.temperature, RECOMBINATION_TEMPERATURE_K)
+    });
+
     suite
 }
 
+// Cada nivel se ejecuta aunque falle el anterior: un fallo atómico no debe ocultar las cotas cosmológicas
 pub fn run_tiered_validation(engine: &AdvancedPhysicsEngine) -> ValidationLevel {
     println!("\n🎯 VALIDACIÓN POR NIVELES DE COMPLEJIDAD");
     
@@ -154,23 +297,19 @@ pub fn run_tiered_validation(engine: &AdvancedPhysicsEngine) -> ValidationLevel
     let mut fundamental_suite = create_fundamental_test_suite();
     let level1_pass = fundamental_suite.run_all_tests(engine);
     
-    if !level1_pass {
-        return ValidationLevel::Failed("Física atómica fundamental");
-    }
-    
     println!("\n📌 NIVEL 2: Cromodinámica Cuántica");
     let mut qcd_suite = create_qcd_test_suite();
     let level2_pass = qcd_suite.run_all_tests(engine);
     
-    if !level2_pass {
-        return ValidationLevel::Partial("QCD requiere calibración");
-    }
-    
     println!("\n📌 NIVEL 3: Cosmología");
     let mut cosmo_suite = create_cosmology_test_suite();
     let level3_pass = cosmo_suite.run_all_tests(engine);
     
-    if level3_pass {
+    if !level1_pass {
+        ValidationLevel::Failed("Física atómica fundamental")
+    } else if !level2_pass {
+        ValidationLevel::Partial("QCD requiere calibración")
+    } else if level3_pass {
         ValidationLevel::Full
     } else {
         ValidationLevel::Partial("Cosmología requiere refinamiento")
@@ -187,11 +326,11 @@ pub enum ValidationLevel {
 impl ValidationLevel {
 }
 
-pub fn run_scientific_validation_mode() -> Result<(), Box<dyn std::error::Error>> {
+pub fn run_scientific_validation_mode(universe_path: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
     println!("🔬 INICIANDO VALIDACIÓN CIENTÍFICA RIGUROSA");
     
-    let reference_universe = create_reference_universe();
-    let engine = AdvancedPhysicsEngine::new(reference_universe);
+    let universe = load_universe(universe_path)?;
+    let engine = AdvancedPhysicsEngine::new(universe);
     
     let validation_result = run_tiered_validation(&engine);
     
@@ -214,29 +353,60 @@ pub fn run_scientific_validation_mode() -> Result<(), Box<dyn std::error::Error>
     Ok(())
 }
 
-fn create_reference_universe() -> CosmicLaw {
-    CosmicLaw {
-        g: G_GRAVITATIONAL,  // Usar constante de constants.rs
-        e: ELEMENTARY_CHARGE, // Usar constante exacta
-       alpha_s: 0.1181, // Valor a escala de la masa del Z. El engine lo hará "correr".
-        alpha_w: 0.03062,
-        
-        mass_up_quark: 2.16 * MEV_TO_KG,
-        mass_down_quark: 4.67 * MEV_TO_KG,
-        mass_electron: ELECTRON_MASS_EXACT,
-        
-        mass_strange_quark: 93.4 * MEV_TO_KG,
-        mass_charm_quark: 1.27 * KG_TO_GEV.recip(), // 1.27 GeV
-        mass_muon: 105.658 * MEV_TO_KG,
-        
-        mass_bottom_quark: 4.18 * KG_TO_GEV.recip(), // 4.18 GeV
-        mass_top_quark: 172.76 * KG_TO_GEV.recip(), // 172.76 GeV
-        mass_tauon: 1776.86 * MEV_TO_KG, // 1.77686 GeV
-        
-        spatial_curvature: 0.0007,
-        dimensional_ratios: [1.0, 1.0, 1.0, 1.0],
-        temporal_evolution_rate: 1.0,
-        dark_energy_density: 1.2e-29, // kg/m³
-        dark_matter_coupling: 0.26,
+pub fn load_universe(universe_path: Option<&str>) -> Result<CosmicLaw, Box<dyn std::error::Error>> {
+    match universe_path {
+        Some(path) => Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?),
+        None => Ok(CosmicLaw::reference()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // None si la prueba no existe o la batería aún no se ha ejecutado
+    fn test_passed(suite: &TestSuite, name: &str) -> Option<bool> {
+        let index = suite.tests.iter().position(|test| test.name == name)?;
+        suite.outcomes.get(index).copied()
+    }
+
+    fn reference_cosmology() -> TestSuite {
+        let mut suite = create_cosmology_test_suite();
+        suite.run_all_tests(&AdvancedPhysicsEngine::new(CosmicLaw::reference()));
+        suite
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn reference_universe_passes_the_relic_and_thermal_checks() {
+        let suite = reference_cosmology();
+        for name in [
+            "Universe Age",
+            "Dark Matter Relic Density",
+            "Thermal Relic Cross Section",
+            "Temperature At One Second",
+            "QCD Transition Temperature",
+            "Weak Freeze-out Temperature",
+            "Deuterium Bottleneck Temperature",
+            "Recombination Temperature",
+        ] {
+            assert_eq!(test_passed(&suite, name), Some(true), "{}", name);
+        }
+        assert_eq!(test_passed(&suite, "no-existe"), None);
+    }
+
+    #[test]
+    fn drift_bounds_pass_only_for_constant_laws() {
+        let suite = reference_cosmology();
+        for name in ["Oklo Alpha Bound", "Quasar Alpha Bound", "Lunar Laser Ranging G Bound"] {
+            assert_eq!(test_passed(&suite, name), Some(true), "{}", name);
+        }
+
+        let mut laws = CosmicLaw::reference();
+        laws.temporal_evolution_rate = 10.0;
+        let mut drifting = create_cosmology_test_suite();
+        drifting.run_all_tests(&AdvancedPhysicsEngine::new(laws));
+        for name in ["Oklo Alpha Bound", "Quasar Alpha Bound", "Lunar Laser Ranging G Bound"] {
+            assert_eq!(test_passed(&drifting, name), Some(false), "{}", name);
+        }
+    }
+}
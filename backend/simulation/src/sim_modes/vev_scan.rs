@@ -0,0 +1,114 @@
+use crate::core::models::{CosmicLaw, HiggsSector};
+use crate::physics::constants::*;
+use crate::physics::engine::{AdvancedPhysicsEngine, calculate_fitness_breakdown};
+use std::error::Error;
+use csv;
+
+const HIGGS_VEV_REFERENCE_GEV: f64 = 246.22;
+
+// Mismos Yukawas que nuestro universo con el vev escalado
+fn universe_at_vev(reference: &CosmicLaw, reference_higgs: &HiggsSector, vev_ratio: f64) -> CosmicLaw {
+    let mut universe = reference.clone();
+    universe.higgs_sector = Some(HiggsSector {
+        higgs_vev: HIGGS_VEV_REFERENCE_GEV * vev_ratio,
+        ..*reference_higgs
+    });
+    universe
+}
+
+// Se varía v con Yukawas fijos, como en el "principio atómico" de Agrawal et al.; los tramos son los de este modelo, no los publicados
+pub fn run_vev_scan_mode(steps: u32, min_ratio: f64, max_ratio: f64) -> Result<(), Box<dyn Error>> {
+    let mut wtr = csv::Writer::from_path("vev_scan.csv")?;
+    wtr.write_record([
+        "vev_ratio",
+        "higgs_vev_gev",
+        "mass_electron_mev",
+        "neutron_proton_gap_mev",
+        "deuteron_binding_mev",
+        "fitness",
+        "complexity_level",
+        "failure_reason",
+    ])?;
+
+    println!("🔭 BARRIDO DEL VEV DE HIGGS: v/v₀ de {} a {}", min_ratio, max_ratio);
+
+    let reference = CosmicLaw::reference();
+    let reference_higgs = HiggsSector::from_masses(&reference, HIGGS_VEV_REFERENCE_GEV);
+
+    // Tramos contiguos de v/v₀ viables; un punto no viable cierra el tramo abierto
+    let mut windows: Vec<(f64, f64)> = Vec::new();
+    let mut open_window: Option<(f64, f64)> = None;
+
+    for step in 0..steps {
+        let fraction = step as f64 / (steps.max(2) - 1) as f64;
+        let vev_ratio = min_ratio * (max_ratio / min_ratio).powf(fraction);
+
+        let universe = universe_at_vev(&reference, &reference_higgs, vev_ratio);
+
+        let engine = AdvancedPhysicsEngine::new(universe.clone());
+        let (m_proton, m_neutron, _) = engine.get_validated_hadron_masses();
+        let breakdown = calculate_fitness_breakdown(&universe);
+
+        // Ventana atómica: hidrógeno estable, deuterón ligado y diprotón libre
+        if breakdown.failure_reason.is_none() {
+            open_window = Some(match open_window {
+                Some((low, _)) => (low, vev_ratio),
+                None => (vev_ratio, vev_ratio),
+            });
+        } else if let Some(window) = open_window.take() {
+            windows.push(window);
+        }
+
+        wtr.write_record(&[
+            format!("{:.4}", vev_ratio),
+            format!("{:.2}", HIGGS_VEV_REFERENCE_GEV * vev_ratio),
+            format!("{:.4}", engine.laws.mass_electron / MEV_TO_KG),
+            format!("{:.4}", (m_neutron - m_proton) / MEV_TO_KG),
+            format!("{:.4}", engine.light_nuclei().deuteron_binding),
+            format!("{:.6}", breakdown.fitness),
+            breakdown.complexity_level.to_string(),
+            breakdown.failure_reason.unwrap_or_default(),
+        ])?;
+    }
+
+    wtr.flush()?;
+    windows.extend(open_window);
+
+    if windows.is_empty() {
+        println!("💀 Ningún valor del vev produce química");
+    } else {
+        println!("🎯 Tramos viables ({}):", windows.len());
+        for (low, high) in &windows {
+            println!("   {:.3} ≤ v/v₀ ≤ {:.3}", low, high);
+        }
+    }
+    println!("📁 Resultados guardados en vev_scan.csv");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_vev_reproduces_our_universe() {
+        let reference = CosmicLaw::reference();
+        let higgs = HiggsSector::from_masses(&reference, HIGGS_VEV_REFERENCE_GEV);
+        let engine = AdvancedPhysicsEngine::new(universe_at_vev(&reference, &higgs, 1.0));
+        assert!((engine.laws.mass_electron / reference.mass_electron - 1.0).abs() < 1e-12);
+        assert!((engine.laws.mass_down_quark / reference.mass_down_quark - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fermion_masses_scale_with_the_vev() {
+        let reference = CosmicLaw::reference();
+        let higgs = HiggsSector::from_masses(&reference, HIGGS_VEV_REFERENCE_GEV);
+        let engine = AdvancedPhysicsEngine::new(universe_at_vev(&reference, &higgs, 3.0));
+        assert!((engine.laws.mass_electron / reference.mass_electron - 3.0).abs() < 1e-9);
+        assert!((engine.laws.mass_up_quark / reference.mass_up_quark - 3.0).abs() < 1e-9);
+        // Quarks más pesados acortan el alcance del pion y el deuterón deja de estar ligado
+        if !cfg!(feature = "empirical_validation") {
+            assert!(!engine.light_nuclei().deuteron_bound());
+        }
+    }
+}
@@ -6,6 +6,17 @@ pub fn analyze_universe_type(level: u8) -> &'static str {
         3 => "Estelar",
         4 => "Complejo",
         5 => "Reproductivo",
+        6 => "Habitable",
         _ => "Trascendente",
     }
-}
\ No newline at end of file
+}
+// K > 0.5 cerrado, K < −0.5 abierto; en medio prácticamente plano
+pub fn curvature_class(spatial_curvature: f64) -> &'static str {
+    if spatial_curvature < -0.5 {
+        "Hyperbolic"
+    } else if spatial_curvature > 0.5 {
+        "Spherical"
+    } else {
+        "Flat"
+    }
+}
@@ -1,2 +1,2 @@
 pub mod helpers;
-pub use helpers::analyze_universe_type;
\ No newline at end of file
+pub use helpers::{analyze_universe_type, curvature_class};
\ No newline at end of file